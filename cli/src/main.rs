@@ -19,10 +19,7 @@ use color_eyre::eyre::{
     WrapErr,
 };
 use frame_metadata::RuntimeMetadataPrefixed;
-use scale::{
-    Decode,
-    Input,
-};
+use scale::Decode;
 use std::{
     fs,
     io::{
@@ -74,6 +71,9 @@ enum Command {
         /// Additional derives
         #[structopt(long = "derive")]
         derives: Vec<String>,
+        /// Also generate the `_cached` and `_json` constant accessors
+        #[structopt(long)]
+        extra_constant_accessors: bool,
     },
 }
 
@@ -106,7 +106,12 @@ fn main() -> color_eyre::Result<()> {
                 }
             }
         }
-        Command::Codegen { url, file, derives } => {
+        Command::Codegen {
+            url,
+            file,
+            derives,
+            extra_constant_accessors,
+        } => {
             if let Some(file) = file.as_ref() {
                 if url.is_some() {
                     eyre::bail!("specify one of `--url` or `--file` but not both")
@@ -115,7 +120,7 @@ fn main() -> color_eyre::Result<()> {
                 let mut file = fs::File::open(file)?;
                 let mut bytes = Vec::new();
                 file.read_to_end(&mut bytes)?;
-                codegen(&mut &bytes[..], derives)?;
+                codegen(&bytes, derives, extra_constant_accessors)?;
                 return Ok(())
             }
 
@@ -123,7 +128,7 @@ fn main() -> color_eyre::Result<()> {
                 url::Url::parse("http://localhost:9933").expect("default url is valid")
             });
             let (_, bytes) = fetch_metadata(&url)?;
-            codegen(&mut &bytes[..], derives)?;
+            codegen(&bytes, derives, extra_constant_accessors)?;
             Ok(())
         }
     }
@@ -149,11 +154,13 @@ fn fetch_metadata(url: &url::Url) -> color_eyre::Result<(String, Vec<u8>)> {
     Ok((hex_data, bytes))
 }
 
-fn codegen<I: Input>(
-    encoded: &mut I,
+fn codegen(
+    encoded: &[u8],
     raw_derives: Vec<String>,
+    extra_constant_accessors: bool,
 ) -> color_eyre::Result<()> {
-    let metadata = <RuntimeMetadataPrefixed as Decode>::decode(encoded)?;
+    subxt_codegen::check_magic_number(encoded).wrap_err("not metadata")?;
+    let metadata = <RuntimeMetadataPrefixed as Decode>::decode(&mut &encoded[..])?;
     let generator = subxt_codegen::RuntimeGenerator::new(metadata);
     let item_mod = syn::parse_quote!(
         pub mod api {}
@@ -166,7 +173,8 @@ fn codegen<I: Input>(
     let mut derives = GeneratedTypeDerives::default();
     derives.append(p.into_iter());
 
-    let runtime_api = generator.generate_runtime(item_mod, derives);
+    let runtime_api =
+        generator.generate_runtime(item_mod, derives, extra_constant_accessors);
     println!("{}", runtime_api);
     Ok(())
 }
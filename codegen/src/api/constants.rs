@@ -14,56 +14,912 @@
 // You should have received a copy of the GNU General Public License
 // along with subxt.  If not, see <http://www.gnu.org/licenses/>.
 
-use crate::types::TypeGenerator;
+use crate::types::{
+    CompositeDefFieldType,
+    TypeGenerator,
+    TypePath,
+};
 use frame_metadata::{
     PalletConstantMetadata,
     PalletMetadata,
 };
-use heck::ToSnakeCase as _;
+use heck::{
+    ToSnakeCase as _,
+    ToUpperCamelCase as _,
+};
 use proc_macro2::TokenStream as TokenStream2;
+use proc_macro_error::abort_call_site;
 use quote::{
     format_ident,
     quote,
+    ToTokens as _,
+};
+use scale_info::{
+    form::PortableForm,
+    TypeDef,
 };
-use scale_info::form::PortableForm;
+use std::collections::HashMap;
 
 pub fn generate_constants(
     type_gen: &TypeGenerator,
     pallet: &PalletMetadata<PortableForm>,
     constants: &[PalletConstantMetadata<PortableForm>],
     types_mod_ident: &syn::Ident,
+    extra_accessors: bool,
 ) -> TokenStream2 {
-    let constant_fns = constants.iter().map(|constant| {
-        let fn_name = format_ident!("{}", constant.name.to_snake_case());
-        let pallet_name = &pallet.name;
-        let constant_name = &constant.name;
-        let return_ty = type_gen.resolve_type_path(constant.ty.id(), &[]);
+    if let Err(err) = check_accessor_names(&pallet.name, constants, extra_accessors) {
+        abort_call_site!("{}", err)
+    }
 
+    let pallet_name = &pallet.name;
+    let constant_values = constants.iter().map(|constant| {
+        let constant_name = &constant.name;
         quote! {
-            pub fn #fn_name(&self) -> ::core::result::Result<#return_ty, ::subxt::BasicError> {
-                let pallet = self.client.metadata().pallet(#pallet_name)?;
-                let constant = pallet.constant(#constant_name)?;
-                let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
-                Ok(value)
-            }
+            constants.push((#constant_name, pallet.constant(#constant_name)?.value.clone()));
         }
     });
+    let (constant_tys, constant_fns): (Vec<_>, Vec<_>) = constants
+        .iter()
+        .map(|constant| {
+            let fn_name = constant_ident(&constant.name.to_snake_case());
+            // Escape the alias as a whole: `SelfTy` is fine as it is, only names starting
+            // with a digit need fixing.
+            let ty_alias =
+                constant_ident(&format!("{}Ty", constant.name.to_upper_camel_case()));
+            let constant_name = &constant.name;
+            let return_ty = type_gen.resolve_type_path(constant.ty.id(), &[]);
+
+            let raw_fn = format_ident!("{}_raw", fn_name);
+
+            let docs = if constant.docs.is_empty() {
+                // Leave at least something for `cargo doc` when the runtime documents nothing.
+                let type_path = quote!(#return_ty).to_string().replace(' ', "");
+                vec![format!(
+                    " The `{}::{}` constant, of type `{}`.",
+                    pallet_name, constant_name, type_path
+                )]
+            } else {
+                constant.docs.clone()
+            };
+            // A doc line starting with `@deprecated` marks the constant as deprecated, with
+            // the rest of the line used as the note.
+            let deprecated = docs
+                .iter()
+                .find_map(|doc| doc.trim().strip_prefix("@deprecated"))
+                .map(|note| {
+                    let note = note.trim();
+                    if note.is_empty() {
+                        quote!(#[deprecated])
+                    } else {
+                        quote!(#[deprecated(note = #note)])
+                    }
+                });
+
+            // Newtypes of primitives (e.g. `struct Balance(u128)`) also get an accessor for
+            // the wrapped value. Substituted types are skipped since their fields may not
+            // be public.
+            let constant_type = type_gen.resolve_type(constant.ty.id());
+            let inner_fn = match (constant_type.type_def(), &return_ty) {
+                (TypeDef::Composite(composite), TypePath::Type(_))
+                    if composite.fields().len() == 1 =>
+                {
+                    let field = &composite.fields()[0];
+                    let is_primitive = matches!(
+                        type_gen.resolve_type(field.ty().id()).type_def(),
+                        TypeDef::Primitive(_) | TypeDef::Compact(_)
+                    );
+                    let field_ty = CompositeDefFieldType::new(
+                        field.ty().id(),
+                        type_gen.resolve_type_path(field.ty().id(), &[]),
+                        field.type_name().cloned(),
+                    );
+                    let field_access = match field.name() {
+                        Some(name) => field_ident(name).map(|ident| ident.into_token_stream()),
+                        None => Some(syn::Index::from(0).into_token_stream()),
+                    };
+                    let inner_fn = format_ident!("{}_inner", fn_name);
+                    // Decode directly rather than via the plain accessor, since calling a
+                    // deprecated function warns even from another deprecated function.
+                    field_access
+                        .filter(|_| is_primitive && !field_ty.is_boxed())
+                        .map(|field_access| {
+                            quote! {
+                                #deprecated
+                                pub fn #inner_fn(&self) -> ::core::result::Result<#field_ty, ::subxt::BasicError> {
+                                    let pallet = self.metadata.pallet(#pallet_name)?;
+                                    let value = pallet.decode_constant::<#ty_alias>(#constant_name)?;
+                                    Ok(value.#field_access)
+                                }
+                            }
+                        })
+                }
+                _ => None,
+            };
+
+            let cached_fn = extra_accessors.then(|| {
+                let cached_fn = format_ident!("{}_cached", fn_name);
+                quote! {
+                    /// Like the uncached accessor, but only decodes the value on first use and
+                    /// returns a reference to it from then on.
+                    #deprecated
+                    pub fn #cached_fn(&self) -> ::core::result::Result<&'a #ty_alias, ::subxt::BasicError> {
+                        let pallet = self.metadata.pallet(#pallet_name)?;
+                        let value = pallet.cached_constant::<#ty_alias>(#constant_name)?;
+                        Ok(value)
+                    }
+                }
+            });
+
+            let json_fn = extra_accessors.then(|| {
+                let json_fn = format_ident!("{}_json", fn_name);
+                quote! {
+                    /// Decode the value as JSON, using the type information in the metadata.
+                    #deprecated
+                    pub fn #json_fn(&self) -> ::core::result::Result<::subxt::serde_json::Value, ::subxt::BasicError> {
+                        self.metadata.constant_json(#pallet_name, #constant_name)
+                    }
+                }
+            });
+
+            let constant_ty = quote! {
+                pub type #ty_alias = #return_ty;
+            };
+            let constant_fn = quote! {
+                #( #[doc = #docs] )*
+                #deprecated
+                pub fn #fn_name(&self) -> ::core::result::Result<#ty_alias, ::subxt::BasicError> {
+                    let pallet = self.metadata.pallet(#pallet_name)?;
+                    let value = pallet.decode_constant::<#ty_alias>(#constant_name)?;
+                    Ok(value)
+                }
+
+                #cached_fn
+
+                #inner_fn
+
+                /// The raw SCALE encoded value, along with the id of its type in the metadata.
+                #deprecated
+                pub fn #raw_fn(&self) -> ::core::result::Result<(::std::vec::Vec<::core::primitive::u8>, ::core::primitive::u32), ::subxt::BasicError> {
+                    let pallet = self.metadata.pallet(#pallet_name)?;
+                    let value = pallet.raw_constant(#constant_name)?;
+                    Ok(value)
+                }
+
+                #json_fn
+            };
+            (constant_ty, constant_fn)
+        })
+        .unzip();
 
     quote! {
         pub mod constants {
             use super::#types_mod_ident;
 
-            pub struct ConstantsApi<'a, T: ::subxt::Config> {
-                client: &'a ::subxt::Client<T>,
+            #( #constant_tys )*
+
+            pub struct ConstantsApi<'a, T> {
+                metadata: &'a ::subxt::Metadata,
+                marker: ::core::marker::PhantomData<T>,
             }
 
             impl<'a, T: ::subxt::Config> ConstantsApi<'a, T> {
                 pub fn new(client: &'a ::subxt::Client<T>) -> Self {
-                    Self { client }
+                    Self::from_metadata(client.metadata())
+                }
+            }
+
+            impl<'a, T> ConstantsApi<'a, T> {
+                /// Read constants straight from `metadata`, without a client to fetch it with.
+                ///
+                /// `T` is only needed by [`Self::new`], so any type will do here.
+                pub fn from_metadata(metadata: &'a ::subxt::Metadata) -> Self {
+                    Self { metadata, marker: ::core::marker::PhantomData }
                 }
 
                 #(#constant_fns)*
+
+                /// Returns the name and raw SCALE encoded value of every constant in this pallet.
+                pub fn all(&self) -> ::core::result::Result<::std::vec::Vec<(&'static str, ::std::vec::Vec<::core::primitive::u8>)>, ::subxt::BasicError> {
+                    let pallet = self.metadata.pallet(#pallet_name)?;
+                    let mut constants = ::std::vec::Vec::new();
+                    #( #constant_values )*
+                    Ok(constants)
+                }
+            }
+        }
+    }
+}
+
+/// Every constant gets a family of accessors (`foo`, `foo_raw`, `foo_inner`...) next to
+/// `ConstantsApi`'s own functions, so some constant names would clash with them; for
+/// instance `All`, or `Foo` next to `FooRaw`. Catch that here rather than generating
+/// code which does not compile.
+fn check_accessor_names(
+    pallet_name: &str,
+    constants: &[PalletConstantMetadata<PortableForm>],
+    extra_accessors: bool,
+) -> Result<(), String> {
+    let mut taken: HashMap<String, String> = ["new", "from_metadata", "all"]
+        .iter()
+        .map(|name| (name.to_string(), format!("ConstantsApi::{}", name)))
+        .collect();
+    for constant in constants {
+        let fn_name = constant_ident(&constant.name.to_snake_case()).to_string();
+        let extra_suffixes: &[&str] = if extra_accessors {
+            &["_cached", "_json"]
+        } else {
+            &[]
+        };
+        let suffixes = ["", "_inner", "_raw"].iter().chain(extra_suffixes);
+        for suffix in suffixes {
+            let accessor = format!("{}{}", fn_name, suffix);
+            let owner = format!("the {}::{} constant", pallet_name, constant.name);
+            if let Some(other) = taken.insert(accessor.clone(), owner) {
+                return Err(format!(
+                    "Accessor `{}` for the {}::{} constant clashes with {}",
+                    accessor, pallet_name, constant.name, other
+                ))
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Turn a constant name into a valid identifier: keywords get a trailing underscore and
+/// names starting with a digit get a leading one.
+fn constant_ident(name: &str) -> syn::Ident {
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        format_ident!("_{}", name)
+    } else if syn::parse_str::<syn::Ident>(name).is_err() {
+        format_ident!("{}_", name)
+    } else {
+        format_ident!("{}", name)
+    }
+}
+
+/// Turn a field name into an identifier to access the field with. Keywords need to be
+/// raw identifiers, and names which cannot be one at all (e.g. `self`) give `None`.
+fn field_ident(name: &str) -> Option<syn::Ident> {
+    syn::parse_str::<syn::Ident>(name)
+        .or_else(|_| syn::parse_str::<syn::Ident>(&format!("r#{}", name)))
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use scale_info::{
+        meta_type,
+        MetaType,
+        PortableRegistry,
+        Registry,
+    };
+
+    fn constant(
+        registry: &mut Registry,
+        name: &str,
+        ty: MetaType,
+    ) -> PalletConstantMetadata<PortableForm> {
+        PalletConstantMetadata {
+            name: name.to_string(),
+            ty: registry.register_type(&ty),
+            value: Vec::new(),
+            docs: Vec::new(),
+        }
+    }
+
+    fn pallet(
+        name: &str,
+        constants: Vec<PalletConstantMetadata<PortableForm>>,
+    ) -> PalletMetadata<PortableForm> {
+        PalletMetadata {
+            name: name.to_string(),
+            storage: None,
+            calls: None,
+            event: None,
+            constants,
+            error: None,
+            index: 0,
+        }
+    }
+
+    fn generate(registry: Registry, pallet: &PalletMetadata<PortableForm>) -> String {
+        generate_with(registry, pallet, false)
+    }
+
+    fn generate_with(
+        registry: Registry,
+        pallet: &PalletMetadata<PortableForm>,
+        extra_accessors: bool,
+    ) -> String {
+        let registry: PortableRegistry = registry.into();
+        let type_gen = TypeGenerator::new(
+            &registry,
+            "runtime_types",
+            Default::default(),
+            Default::default(),
+        );
+        let types_mod_ident = format_ident!("runtime_types");
+        generate_constants(
+            &type_gen,
+            pallet,
+            &pallet.constants,
+            &types_mod_ident,
+            extra_accessors,
+        )
+        .to_string()
+    }
+
+    #[test]
+    fn generate_constant_accessors() {
+        let mut registry = Registry::new();
+        let constants = vec![constant(
+            &mut registry,
+            "ExistentialDeposit",
+            meta_type::<u128>(),
+        )];
+        let pallet = pallet("Balances", constants);
+
+        assert_eq!(
+            generate(registry, &pallet),
+            quote! {
+                pub mod constants {
+                    use super::runtime_types;
+
+                    pub type ExistentialDepositTy = ::core::primitive::u128;
+
+                    pub struct ConstantsApi<'a, T> {
+                        metadata: &'a ::subxt::Metadata,
+                        marker: ::core::marker::PhantomData<T>,
+                    }
+
+                    impl<'a, T: ::subxt::Config> ConstantsApi<'a, T> {
+                        pub fn new(client: &'a ::subxt::Client<T>) -> Self {
+                            Self::from_metadata(client.metadata())
+                        }
+                    }
+
+                    impl<'a, T> ConstantsApi<'a, T> {
+                        /// Read constants straight from `metadata`, without a client to fetch it with.
+                        ///
+                        /// `T` is only needed by [`Self::new`], so any type will do here.
+                        pub fn from_metadata(metadata: &'a ::subxt::Metadata) -> Self {
+                            Self { metadata, marker: ::core::marker::PhantomData }
+                        }
+
+                        #[doc = " The `Balances::ExistentialDeposit` constant, of type `::core::primitive::u128`."]
+                        pub fn existential_deposit(&self) -> ::core::result::Result<ExistentialDepositTy, ::subxt::BasicError> {
+                            let pallet = self.metadata.pallet("Balances")?;
+                            let value = pallet.decode_constant::<ExistentialDepositTy>("ExistentialDeposit")?;
+                            Ok(value)
+                        }
+
+                        /// The raw SCALE encoded value, along with the id of its type in the metadata.
+                        pub fn existential_deposit_raw(&self) -> ::core::result::Result<(::std::vec::Vec<::core::primitive::u8>, ::core::primitive::u32), ::subxt::BasicError> {
+                            let pallet = self.metadata.pallet("Balances")?;
+                            let value = pallet.raw_constant("ExistentialDeposit")?;
+                            Ok(value)
+                        }
+
+                        /// Returns the name and raw SCALE encoded value of every constant in this pallet.
+                        pub fn all(&self) -> ::core::result::Result<::std::vec::Vec<(&'static str, ::std::vec::Vec<::core::primitive::u8>)>, ::subxt::BasicError> {
+                            let pallet = self.metadata.pallet("Balances")?;
+                            let mut constants = ::std::vec::Vec::new();
+                            constants.push(("ExistentialDeposit", pallet.constant("ExistentialDeposit")?.value.clone()));
+                            Ok(constants)
+                        }
+                    }
+                }
+            }
+            .to_string()
+        )
+    }
+
+    #[test]
+    fn constants_are_read_from_metadata_alone() {
+        let mut registry = Registry::new();
+        let constants = vec![
+            constant(&mut registry, "ExistentialDeposit", meta_type::<u128>()),
+            constant(&mut registry, "MaxLocks", meta_type::<u32>()),
+        ];
+        let pallet = pallet("Balances", constants);
+
+        let generated = generate(registry, &pallet);
+        // The api only holds the metadata; a client is just one way of getting it.
+        let expected = [
+            quote! {
+                pub struct ConstantsApi<'a, T> {
+                    metadata: &'a ::subxt::Metadata,
+                    marker: ::core::marker::PhantomData<T>,
+                }
+            },
+            quote! {
+                impl<'a, T: ::subxt::Config> ConstantsApi<'a, T> {
+                    pub fn new(client: &'a ::subxt::Client<T>) -> Self {
+                        Self::from_metadata(client.metadata())
+                    }
+                }
+            },
+            // Offline users have no `Config` to hand, so don't demand one.
+            quote! {
+                impl<'a, T> ConstantsApi<'a, T>
+            },
+            quote! {
+                pub fn from_metadata(metadata: &'a ::subxt::Metadata) -> Self {
+                    Self { metadata, marker: ::core::marker::PhantomData }
+                }
+            },
+        ];
+        for tokens in expected {
+            assert!(generated.contains(&tokens.to_string()), "{}", generated);
+        }
+    }
+
+    #[test]
+    fn same_constant_name_in_different_pallets() {
+        use crate::{
+            GeneratedTypeDerives,
+            RuntimeGenerator,
+        };
+        use frame_metadata::{
+            v14::RuntimeMetadataV14,
+            ExtrinsicMetadata,
+        };
+        use scale_info::{
+            build::{
+                Fields,
+                Variants,
+            },
+            Path,
+            Type,
+            TypeInfo,
+        };
+
+        // The runtime codegen needs a `DispatchError` to implement `HasModuleError` for.
+        struct DispatchError;
+        impl TypeInfo for DispatchError {
+            type Identity = Self;
+
+            fn type_info() -> Type {
+                Type::builder()
+                    .path(Path::new("DispatchError", "sp_runtime"))
+                    .variant(Variants::new().variant("Module", |v| {
+                        v.index(0).fields(
+                            Fields::named()
+                                .field(|f| f.ty::<u8>().name("index"))
+                                .field(|f| f.ty::<u8>().name("error")),
+                        )
+                    }))
+            }
+        }
+
+        let max_locks = || {
+            PalletConstantMetadata {
+                name: "MaxLocks",
+                ty: meta_type::<u32>(),
+                value: 50u32.to_le_bytes().to_vec(),
+                docs: Vec::new(),
+            }
+        };
+        let pallet = |name, index| {
+            frame_metadata::PalletMetadata {
+                name,
+                storage: None,
+                calls: None,
+                event: None,
+                constants: vec![max_locks()],
+                error: None,
+                index,
+            }
+        };
+        let extrinsic = ExtrinsicMetadata {
+            ty: meta_type::<()>(),
+            version: 4,
+            signed_extensions: Vec::new(),
+        };
+        let metadata = RuntimeMetadataV14::new(
+            vec![pallet("Balances", 0), pallet("Vesting", 1)],
+            extrinsic,
+            meta_type::<DispatchError>(),
+        );
+
+        let generated = RuntimeGenerator::new(metadata.into())
+            .generate_runtime(
+                syn::parse_quote!(
+                    pub mod api {}
+                ),
+                GeneratedTypeDerives::default(),
+                false,
+            )
+            .to_string();
+        let file = syn::parse_str::<syn::File>(&generated)
+            .unwrap_or_else(|e| panic!("Invalid generated code: {}\n{}", e, generated));
+
+        let module = |items: &[syn::Item], name: &str| -> Vec<syn::Item> {
+            items
+                .iter()
+                .find_map(|item| {
+                    match item {
+                        syn::Item::Mod(module) if module.ident == name => {
+                            module.content.as_ref().map(|(_, items)| items.clone())
+                        }
+                        _ => None,
+                    }
+                })
+                .unwrap_or_else(|| panic!("No `{}` module in:\n{}", name, generated))
+        };
+        let api = module(&file.items, "api");
+        // Both pallets get their own `max_locks`, reading from their own pallet.
+        for (mod_name, pallet_name) in [("balances", "Balances"), ("vesting", "Vesting")]
+        {
+            let constants = module(&module(&api, mod_name), "constants");
+            let max_locks_fn = constants
+                .iter()
+                .filter_map(|item| {
+                    match item {
+                        syn::Item::Impl(item_impl) => Some(&item_impl.items),
+                        _ => None,
+                    }
+                })
+                .flatten()
+                .find_map(|item| {
+                    match item {
+                        syn::ImplItem::Method(method)
+                            if method.sig.ident == "max_locks" =>
+                        {
+                            Some(method)
+                        }
+                        _ => None,
+                    }
+                })
+                .unwrap_or_else(|| {
+                    panic!("No `{}::constants` max_locks in:\n{}", mod_name, generated)
+                });
+            let read = quote!(self.metadata.pallet(#pallet_name)?).to_string();
+            let body = max_locks_fn.block.to_token_stream().to_string();
+            assert!(body.contains(&read), "{}", body);
+        }
+    }
+
+    #[test]
+    fn all_lists_every_constant() {
+        let mut registry = Registry::new();
+        let constants = vec![
+            constant(&mut registry, "ExistentialDeposit", meta_type::<u128>()),
+            constant(&mut registry, "MaxLocks", meta_type::<u32>()),
+        ];
+        let pallet = pallet("Balances", constants);
+
+        let generated = generate(registry, &pallet);
+        let pushes = quote! {
+            constants.push(("ExistentialDeposit", pallet.constant("ExistentialDeposit")?.value.clone()));
+            constants.push(("MaxLocks", pallet.constant("MaxLocks")?.value.clone()));
+            Ok(constants)
+        };
+        assert!(generated.contains(&pushes.to_string()), "{}", generated);
+    }
+
+    #[test]
+    fn constant_docs_are_generated_in_order() {
+        let mut registry = Registry::new();
+        let mut existential_deposit =
+            constant(&mut registry, "ExistentialDeposit", meta_type::<u128>());
+        existential_deposit.docs = vec![
+            " The minimum amount required to keep an account open.".to_string(),
+            " Accounts below it are reaped.".to_string(),
+        ];
+        let pallet = pallet("Balances", vec![existential_deposit]);
+
+        let generated = generate(registry, &pallet);
+        let docs = quote! {
+            #[doc = " The minimum amount required to keep an account open."]
+            #[doc = " Accounts below it are reaped."]
+            pub fn existential_deposit(&self)
+        };
+        assert!(generated.contains(&docs.to_string()), "{}", generated);
+        assert!(!generated.contains("constant, of type"), "{}", generated);
+    }
+
+    #[test]
+    fn deprecated_marker_in_docs_deprecates_accessor() {
+        let mut registry = Registry::new();
+        let mut max_locks = constant(&mut registry, "MaxLocks", meta_type::<u32>());
+        max_locks.docs = vec![
+            " The maximum number of locks per account.".to_string(),
+            " @deprecated Use `MaxReserves` instead.".to_string(),
+        ];
+        let mut max_reserves = constant(&mut registry, "MaxReserves", meta_type::<u32>());
+        max_reserves.docs =
+            vec![" The maximum number of reserves per account.".to_string()];
+        let pallet = pallet("Balances", vec![max_locks, max_reserves]);
+
+        let generated = generate(registry, &pallet);
+        let deprecated = quote! {
+            #[doc = " @deprecated Use `MaxReserves` instead."]
+            #[deprecated(note = "Use `MaxReserves` instead.")]
+            pub fn max_locks(&self)
+        };
+        assert!(generated.contains(&deprecated.to_string()), "{}", generated);
+        // The accessor and all of its helpers are deprecated.
+        assert_eq!(
+            generated.matches("deprecated (").count(),
+            2,
+            "{}",
+            generated
+        );
+    }
+
+    #[test]
+    fn constant_type_alias_uses_resolved_type_path() {
+        #[allow(unused)]
+        #[derive(scale_info::TypeInfo)]
+        struct Perbill(u32);
+
+        let mut registry = Registry::new();
+        let constants = vec![constant(&mut registry, "Slash", meta_type::<Perbill>())];
+        let pallet = pallet("Staking", constants);
+
+        let generated = generate(registry, &pallet);
+        let alias = quote! {
+            pub type SlashTy = runtime_types::subxt_codegen::api::constants::tests::Perbill;
+        };
+        assert!(generated.contains(&alias.to_string()), "{}", generated);
+        // The accessors refer to the alias rather than spelling out the type again.
+        let signature = quote! {
+            pub fn slash(&self) -> ::core::result::Result<SlashTy, ::subxt::BasicError>
+        };
+        assert!(generated.contains(&signature.to_string()), "{}", generated);
+        assert!(
+            generated.contains(&quote!(decode_constant::<SlashTy>).to_string()),
+            "{}",
+            generated
+        );
+    }
+
+    #[test]
+    fn newtype_constants_get_inner_accessor() {
+        #[allow(unused)]
+        #[derive(scale_info::TypeInfo)]
+        struct Perbill(u32);
+
+        #[allow(unused)]
+        #[derive(scale_info::TypeInfo)]
+        struct Weight {
+            ref_time: u64,
+        }
+
+        #[allow(unused)]
+        #[derive(scale_info::TypeInfo)]
+        struct Balance(#[codec(compact)] u128);
+
+        #[allow(unused)]
+        #[derive(scale_info::TypeInfo)]
+        struct Kind {
+            r#type: u8,
+        }
+
+        let mut registry = Registry::new();
+        let constants = vec![
+            constant(&mut registry, "Slash", meta_type::<Perbill>()),
+            constant(&mut registry, "MaxWeight", meta_type::<Weight>()),
+            constant(&mut registry, "MinBond", meta_type::<Balance>()),
+            constant(&mut registry, "Kind", meta_type::<Kind>()),
+        ];
+        let pallet = pallet("Staking", constants);
+
+        let generated = generate(registry, &pallet);
+        let inner_fns = [
+            quote! {
+                pub fn slash_inner(&self) -> ::core::result::Result<::core::primitive::u32, ::subxt::BasicError> {
+                    let pallet = self.metadata.pallet("Staking")?;
+                    let value = pallet.decode_constant::<SlashTy>("Slash")?;
+                    Ok(value.0)
+                }
+            },
+            quote! {
+                pub fn max_weight_inner(&self) -> ::core::result::Result<::core::primitive::u64, ::subxt::BasicError> {
+                    let pallet = self.metadata.pallet("Staking")?;
+                    let value = pallet.decode_constant::<MaxWeightTy>("MaxWeight")?;
+                    Ok(value.ref_time)
+                }
+            },
+            quote! {
+                pub fn min_bond_inner(&self) -> ::core::result::Result<::core::primitive::u128, ::subxt::BasicError> {
+                    let pallet = self.metadata.pallet("Staking")?;
+                    let value = pallet.decode_constant::<MinBondTy>("MinBond")?;
+                    Ok(value.0)
+                }
+            },
+            quote! {
+                pub fn kind_inner(&self) -> ::core::result::Result<::core::primitive::u8, ::subxt::BasicError> {
+                    let pallet = self.metadata.pallet("Staking")?;
+                    let value = pallet.decode_constant::<KindTy>("Kind")?;
+                    Ok(value.r#type)
+                }
+            },
+        ];
+        for inner_fn in inner_fns {
+            assert!(generated.contains(&inner_fn.to_string()), "{}", generated);
+        }
+    }
+
+    #[test]
+    fn only_primitive_newtypes_get_inner_accessor() {
+        #[allow(unused)]
+        #[derive(scale_info::TypeInfo)]
+        struct PerDispatchClass<T> {
+            normal: T,
+        }
+
+        #[allow(unused)]
+        #[derive(scale_info::TypeInfo)]
+        struct BlockLength {
+            max: PerDispatchClass<u32>,
+        }
+
+        let mut registry = Registry::new();
+        let constants = vec![
+            constant(&mut registry, "BlockLength", meta_type::<BlockLength>()),
+            constant(
+                &mut registry,
+                "MaxLength",
+                meta_type::<PerDispatchClass<u32>>(),
+            ),
+        ];
+        let pallet = pallet("System", constants);
+
+        let generated = generate(registry, &pallet);
+        // `BlockLength` wraps a struct rather than a primitive, so it gets no accessor.
+        assert!(!generated.contains("block_length_inner"), "{}", generated);
+        assert!(
+            generated.contains("pub fn max_length_inner"),
+            "{}",
+            generated
+        );
+    }
+
+    #[test]
+    fn deprecated_newtype_inner_accessor_does_not_call_accessor() {
+        #[allow(unused)]
+        #[derive(scale_info::TypeInfo)]
+        struct Perbill(u32);
+
+        let mut registry = Registry::new();
+        let mut slash = constant(&mut registry, "Slash", meta_type::<Perbill>());
+        slash.docs = vec![" @deprecated Use `SlashFraction` instead.".to_string()];
+        let pallet = pallet("Staking", vec![slash]);
+
+        let generated = generate(registry, &pallet);
+        let inner_fn = quote! {
+            #[deprecated(note = "Use `SlashFraction` instead.")]
+            pub fn slash_inner(&self) -> ::core::result::Result<::core::primitive::u32, ::subxt::BasicError> {
+                let pallet = self.metadata.pallet("Staking")?;
+                let value = pallet.decode_constant::<SlashTy>("Slash")?;
+                Ok(value.0)
             }
+        };
+        assert!(generated.contains(&inner_fn.to_string()), "{}", generated);
+        assert!(!generated.contains("self . slash ()"), "{}", generated);
+    }
+
+    #[test]
+    fn clashing_accessor_names_are_rejected() {
+        let mut registry = Registry::new();
+        let ok = vec![
+            constant(&mut registry, "ExistentialDeposit", meta_type::<u128>()),
+            constant(&mut registry, "MaxLocks", meta_type::<u32>()),
+        ];
+        assert_eq!(check_accessor_names("Balances", &ok, true), Ok(()));
+
+        let all = vec![constant(&mut registry, "All", meta_type::<u32>())];
+        assert_eq!(
+            check_accessor_names("Balances", &all, false),
+            Err(
+                "Accessor `all` for the Balances::All constant clashes with \
+                 ConstantsApi::all"
+                    .to_string()
+            )
+        );
+
+        let raw = vec![
+            constant(&mut registry, "Foo", meta_type::<u32>()),
+            constant(&mut registry, "FooRaw", meta_type::<u32>()),
+        ];
+        assert_eq!(
+            check_accessor_names("Balances", &raw, false),
+            Err(
+                "Accessor `foo_raw` for the Balances::FooRaw constant clashes with \
+                 the Balances::Foo constant"
+                    .to_string()
+            )
+        );
+
+        // `_cached` and `_json` accessors only exist, and so only clash, when asked for.
+        let cached = vec![
+            constant(&mut registry, "Foo", meta_type::<u32>()),
+            constant(&mut registry, "FooCached", meta_type::<u32>()),
+        ];
+        assert_eq!(check_accessor_names("Balances", &cached, false), Ok(()));
+        assert_eq!(
+            check_accessor_names("Balances", &cached, true),
+            Err(
+                "Accessor `foo_cached` for the Balances::FooCached constant clashes \
+                 with the Balances::Foo constant"
+                    .to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn extra_accessors_are_opt_in() {
+        let setup = || {
+            let mut registry = Registry::new();
+            let constants = vec![constant(
+                &mut registry,
+                "ExistentialDeposit",
+                meta_type::<u128>(),
+            )];
+            (registry, pallet("Balances", constants))
+        };
+        let extra_fns = [
+            quote! {
+                pub fn existential_deposit_cached(&self) -> ::core::result::Result<&'a ExistentialDepositTy, ::subxt::BasicError> {
+                    let pallet = self.metadata.pallet("Balances")?;
+                    let value = pallet.cached_constant::<ExistentialDepositTy>("ExistentialDeposit")?;
+                    Ok(value)
+                }
+            },
+            quote! {
+                pub fn existential_deposit_json(&self) -> ::core::result::Result<::subxt::serde_json::Value, ::subxt::BasicError> {
+                    self.metadata.constant_json("Balances", "ExistentialDeposit")
+                }
+            },
+        ];
+
+        let (registry, pallet) = setup();
+        let generated = generate(registry, &pallet);
+        for name in ["existential_deposit_cached", "existential_deposit_json"] {
+            assert!(!generated.contains(name), "{}", generated);
+        }
+
+        let (registry, pallet) = setup();
+        let generated = generate_with(registry, &pallet, true);
+        for extra_fn in extra_fns {
+            assert!(generated.contains(&extra_fn.to_string()), "{}", generated);
+        }
+    }
+
+    #[test]
+    fn field_names_are_escaped() {
+        assert_eq!(field_ident("ref_time"), Some(format_ident!("ref_time")));
+        assert_eq!(field_ident("type"), Some(format_ident!("r#type")));
+        assert_eq!(field_ident("r#type"), Some(format_ident!("r#type")));
+        assert_eq!(field_ident("self"), None);
+    }
+
+    #[test]
+    fn constant_names_are_escaped() {
+        let mut registry = Registry::new();
+        let constants = vec![
+            constant(&mut registry, "Self", meta_type::<u32>()),
+            constant(&mut registry, "Type", meta_type::<u32>()),
+            constant(&mut registry, "3d", meta_type::<u32>()),
+        ];
+        let pallet = pallet("Test", constants);
+
+        let generated = generate(registry, &pallet);
+        syn::parse_str::<syn::File>(&generated)
+            .unwrap_or_else(|e| panic!("Invalid generated code: {}\n{}", e, generated));
+        for (fn_name, ty_alias) in
+            [("self_", "SelfTy"), ("type_", "TypeTy"), ("_3d", "_3dTy")]
+        {
+            let fn_name = format_ident!("{}", fn_name);
+            let ty_alias = format_ident!("{}", ty_alias);
+            let signature = quote! {
+                pub fn #fn_name(&self) -> ::core::result::Result<#ty_alias, ::subxt::BasicError>
+            };
+            assert!(generated.contains(&signature.to_string()), "{}", generated);
         }
     }
 }
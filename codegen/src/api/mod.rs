@@ -51,6 +51,7 @@ use frame_metadata::{
     v14::RuntimeMetadataV14,
     RuntimeMetadata,
     RuntimeMetadataPrefixed,
+    META_RESERVED,
 };
 use heck::ToSnakeCase as _;
 use proc_macro2::TokenStream as TokenStream2;
@@ -61,6 +62,7 @@ use quote::{
 };
 use std::{
     collections::HashMap,
+    fmt,
     fs,
     io::Read,
     path,
@@ -75,6 +77,7 @@ pub fn generate_runtime_api<P>(
     item_mod: syn::ItemMod,
     path: P,
     generated_type_derives: Option<Punctuated<syn::Path, syn::Token![,]>>,
+    extra_constant_accessors: bool,
 ) -> TokenStream2
 where
     P: AsRef<path::Path>,
@@ -87,6 +90,14 @@ where
     file.read_to_end(&mut bytes)
         .unwrap_or_else(|e| abort_call_site!("Failed to read metadata file: {}", e));
 
+    check_magic_number(&bytes).unwrap_or_else(|e| {
+        abort_call_site!(
+            "{} is not a metadata file: {}",
+            path.as_ref().to_string_lossy(),
+            e
+        )
+    });
+
     let metadata = frame_metadata::RuntimeMetadataPrefixed::decode(&mut &bytes[..])
         .unwrap_or_else(|e| abort_call_site!("Failed to decode metadata: {}", e));
 
@@ -96,7 +107,31 @@ where
     }
 
     let generator = RuntimeGenerator::new(metadata);
-    generator.generate_runtime(item_mod, derives)
+    generator.generate_runtime(item_mod, derives, extra_constant_accessors)
+}
+
+/// Error returned by [`check_magic_number`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidMagic;
+
+impl fmt::Display for InvalidMagic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "missing the metadata magic number")
+    }
+}
+
+impl std::error::Error for InvalidMagic {}
+
+/// Check that encoded metadata starts with the metadata magic number (`meta`).
+///
+/// Decoding arbitrary bytes as [`RuntimeMetadataPrefixed`] can succeed and produce
+/// nonsense, so this should be checked before decoding.
+pub fn check_magic_number(bytes: &[u8]) -> Result<(), InvalidMagic> {
+    if bytes.starts_with(&META_RESERVED.to_le_bytes()) {
+        Ok(())
+    } else {
+        Err(InvalidMagic)
+    }
 }
 
 pub struct RuntimeGenerator {
@@ -111,10 +146,15 @@ impl RuntimeGenerator {
         }
     }
 
+    /// Generate the runtime API.
+    ///
+    /// `extra_constant_accessors` additionally generates the `_cached` and `_json`
+    /// variants of every constant accessor.
     pub fn generate_runtime(
         &self,
         item_mod: syn::ItemMod,
         derives: GeneratedTypeDerives,
+        extra_constant_accessors: bool,
     ) -> TokenStream2 {
         let item_mod_ir = ir::ItemMod::from(item_mod);
 
@@ -206,6 +246,7 @@ impl RuntimeGenerator {
                     pallet,
                     &pallet.constants,
                     types_mod_ident,
+                    extra_constant_accessors,
                 )
             } else {
                 quote!()
@@ -302,7 +343,7 @@ impl RuntimeGenerator {
                     X: ::subxt::extrinsic::ExtrinsicParams<T>,
                 {
                     pub fn constants(&'a self) -> ConstantsApi<'a, T> {
-                        ConstantsApi { client: &self.client }
+                        ConstantsApi::new(&self.client)
                     }
 
                     pub fn storage(&'a self) -> StorageApi<'a, T> {
@@ -336,14 +377,28 @@ impl RuntimeGenerator {
                     }
                 }
 
-                pub struct ConstantsApi<'a, T: ::subxt::Config> {
-                    client: &'a ::subxt::Client<T>,
+                pub struct ConstantsApi<'a, T> {
+                    metadata: &'a ::subxt::Metadata,
+                    marker: ::core::marker::PhantomData<T>,
                 }
 
                 impl<'a, T: ::subxt::Config> ConstantsApi<'a, T> {
+                    pub fn new(client: &'a ::subxt::Client<T>) -> Self {
+                        Self::from_metadata(client.metadata())
+                    }
+                }
+
+                impl<'a, T> ConstantsApi<'a, T> {
+                    /// Read constants straight from `metadata`, without a client to fetch it with.
+                    ///
+                    /// `T` is only needed by [`Self::new`], so any type will do here.
+                    pub fn from_metadata(metadata: &'a ::subxt::Metadata) -> Self {
+                        Self { metadata, marker: ::core::marker::PhantomData }
+                    }
+
                     #(
                         pub fn #pallets_with_constants(&self) -> #pallets_with_constants::constants::ConstantsApi<'a, T> {
-                            #pallets_with_constants::constants::ConstantsApi::new(self.client)
+                            #pallets_with_constants::constants::ConstantsApi::from_metadata(self.metadata)
                         }
                     )*
                 }
@@ -422,3 +477,19 @@ where
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn magic_number_is_checked() {
+        let mut bytes = META_RESERVED.to_le_bytes().to_vec();
+        bytes.push(14);
+        assert_eq!(check_magic_number(&bytes), Ok(()));
+        assert_eq!(check_magic_number(&bytes[..3]), Err(InvalidMagic));
+
+        bytes[3] = 0;
+        assert_eq!(check_magic_number(&bytes), Err(InvalidMagic));
+    }
+}
@@ -22,7 +22,9 @@ mod types;
 
 pub use self::{
     api::{
+        check_magic_number,
         generate_runtime_api,
+        InvalidMagic,
         RuntimeGenerator,
     },
     types::{
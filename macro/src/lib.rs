@@ -29,6 +29,8 @@ struct RuntimeMetadataArgs {
     runtime_metadata_path: String,
     #[darling(default)]
     generated_type_derives: Option<GeneratedTypeDerives>,
+    #[darling(default)]
+    extra_constant_accessors: bool,
 }
 
 #[derive(Debug, FromMeta)]
@@ -50,5 +52,11 @@ pub fn subxt(args: TokenStream, input: TokenStream) -> TokenStream {
 
     let generated_type_derives = args.generated_type_derives.map(|derives| derives.0);
 
-    subxt_codegen::generate_runtime_api(item_mod, &path, generated_type_derives).into()
+    subxt_codegen::generate_runtime_api(
+        item_mod,
+        &path,
+        generated_type_derives,
+        args.extra_constant_accessors,
+    )
+    .into()
 }
@@ -30,8 +30,11 @@ use codec::{
     Decode,
 };
 use scale_info::{
+    form::PortableForm,
     PortableRegistry,
     TypeDef,
+    TypeDefBitSequence,
+    TypeDefCompact,
     TypeDefPrimitive,
 };
 
@@ -126,89 +129,105 @@ pub fn decode_and_consume_type(
             }
         }
         TypeDef::Compact(compact) => {
-            let inner = types
-                .resolve(compact.type_param().id())
-                .ok_or(MetadataError::TypeNotFound(type_id))?;
-            let mut decode_compact_primitive = |primitive: &TypeDefPrimitive| {
-                match primitive {
-                    TypeDefPrimitive::U8 => consume_type::<Compact<u8>>(input),
-                    TypeDefPrimitive::U16 => consume_type::<Compact<u16>>(input),
-                    TypeDefPrimitive::U32 => consume_type::<Compact<u32>>(input),
-                    TypeDefPrimitive::U64 => consume_type::<Compact<u64>>(input),
-                    TypeDefPrimitive::U128 => consume_type::<Compact<u128>>(input),
-                    prim => {
-                        Err(EventsDecodingError::InvalidCompactPrimitive(prim.clone())
-                            .into())
-                    }
-                }
-            };
-            match inner.type_def() {
-                TypeDef::Primitive(primitive) => decode_compact_primitive(primitive),
-                TypeDef::Composite(composite) => {
-                    match composite.fields() {
-                        [field] => {
-                            let field_ty =
-                                types.resolve(field.ty().id()).ok_or_else(|| {
-                                    MetadataError::TypeNotFound(field.ty().id())
-                                })?;
-                            if let TypeDef::Primitive(primitive) = field_ty.type_def() {
-                                decode_compact_primitive(primitive)
-                            } else {
-                                Err(EventsDecodingError::InvalidCompactType(
-                                    "Composite type must have a single primitive field"
-                                        .into(),
-                                )
-                                .into())
-                            }
-                        }
-                        _ => {
-                            Err(EventsDecodingError::InvalidCompactType(
-                                "Composite type must have a single field".into(),
-                            )
-                            .into())
-                        }
-                    }
-                }
-                _ => {
-                    Err(EventsDecodingError::InvalidCompactType(
-                        "Compact type must be a primitive or a composite type".into(),
-                    )
-                    .into())
+            match compact_primitive(type_id, compact, types)? {
+                TypeDefPrimitive::U8 => consume_type::<Compact<u8>>(input),
+                TypeDefPrimitive::U16 => consume_type::<Compact<u16>>(input),
+                TypeDefPrimitive::U32 => consume_type::<Compact<u32>>(input),
+                TypeDefPrimitive::U64 => consume_type::<Compact<u64>>(input),
+                TypeDefPrimitive::U128 => consume_type::<Compact<u128>>(input),
+                prim => {
+                    Err(EventsDecodingError::InvalidCompactPrimitive(prim.clone()).into())
                 }
             }
         }
         TypeDef::BitSequence(bitseq) => {
-            let bit_store_def = types
-                .resolve(bitseq.bit_store_type().id())
-                .ok_or(MetadataError::TypeNotFound(type_id))?
-                .type_def();
-
             // We just need to consume the correct number of bytes. Roughly, we encode this
             // as a Compact<u32> length, and then a slice of T of that length, where T is the
             // bit store type. So, we ignore the bit order and only care that the bit store type
             // used lines up in terms of the number of bytes it will take to encode/decode it.
-            match bit_store_def {
-                TypeDef::Primitive(TypeDefPrimitive::U8) => {
-                    consume_type::<BitVec<u8, Lsb0>>(input)
-                }
-                TypeDef::Primitive(TypeDefPrimitive::U16) => {
-                    consume_type::<BitVec<u16, Lsb0>>(input)
-                }
-                TypeDef::Primitive(TypeDefPrimitive::U32) => {
-                    consume_type::<BitVec<u32, Lsb0>>(input)
-                }
-                TypeDef::Primitive(TypeDefPrimitive::U64) => {
-                    consume_type::<BitVec<u64, Lsb0>>(input)
+            match bit_store_type(type_id, bitseq, types)? {
+                BitStoreType::U8 => consume_type::<BitVec<u8, Lsb0>>(input),
+                BitStoreType::U16 => consume_type::<BitVec<u16, Lsb0>>(input),
+                BitStoreType::U32 => consume_type::<BitVec<u32, Lsb0>>(input),
+                BitStoreType::U64 => consume_type::<BitVec<u64, Lsb0>>(input),
+            }
+        }
+    }
+}
+
+/// Resolve the primitive that a compact type is encoded as: either the type parameter
+/// itself, or the single primitive field of a composite like `struct Perbill(u32)`.
+pub(crate) fn compact_primitive<'a>(
+    type_id: u32,
+    compact: &TypeDefCompact<PortableForm>,
+    types: &'a PortableRegistry,
+) -> Result<&'a TypeDefPrimitive, BasicError> {
+    let inner = types
+        .resolve(compact.type_param().id())
+        .ok_or(MetadataError::TypeNotFound(type_id))?;
+    match inner.type_def() {
+        TypeDef::Primitive(primitive) => Ok(primitive),
+        TypeDef::Composite(composite) => {
+            match composite.fields() {
+                [field] => {
+                    let field_ty = types
+                        .resolve(field.ty().id())
+                        .ok_or_else(|| MetadataError::TypeNotFound(field.ty().id()))?;
+                    if let TypeDef::Primitive(primitive) = field_ty.type_def() {
+                        Ok(primitive)
+                    } else {
+                        Err(EventsDecodingError::InvalidCompactType(
+                            "Composite type must have a single primitive field".into(),
+                        )
+                        .into())
+                    }
                 }
-                store => {
-                    return Err(EventsDecodingError::InvalidBitSequenceType(format!(
-                        "{:?}",
-                        store
-                    ))
+                _ => {
+                    Err(EventsDecodingError::InvalidCompactType(
+                        "Composite type must have a single field".into(),
+                    )
                     .into())
                 }
             }
         }
+        _ => {
+            Err(EventsDecodingError::InvalidCompactType(
+                "Compact type must be a primitive or a composite type".into(),
+            )
+            .into())
+        }
+    }
+}
+
+/// The primitive types a bit sequence can store its bits in.
+pub(crate) enum BitStoreType {
+    U8,
+    U16,
+    U32,
+    U64,
+}
+
+/// Resolve the type a bit sequence stores its bits in.
+pub(crate) fn bit_store_type(
+    type_id: u32,
+    bitseq: &TypeDefBitSequence<PortableForm>,
+    types: &PortableRegistry,
+) -> Result<BitStoreType, BasicError> {
+    let bit_store_def = types
+        .resolve(bitseq.bit_store_type().id())
+        .ok_or(MetadataError::TypeNotFound(type_id))?
+        .type_def();
+    match bit_store_def {
+        TypeDef::Primitive(TypeDefPrimitive::U8) => Ok(BitStoreType::U8),
+        TypeDef::Primitive(TypeDefPrimitive::U16) => Ok(BitStoreType::U16),
+        TypeDef::Primitive(TypeDefPrimitive::U32) => Ok(BitStoreType::U32),
+        TypeDef::Primitive(TypeDefPrimitive::U64) => Ok(BitStoreType::U64),
+        store => {
+            Err(
+                EventsDecodingError::InvalidBitSequenceType(format!("{:?}", store))
+                    .into(),
+            )
+        }
     }
 }
 
@@ -227,6 +246,9 @@ pub enum EventsDecodingError {
     /// Invalid bit sequence type; bit store type or bit order type used aren't supported.
     #[error("Invalid bit sequence type; bit store type {0} is not supported")]
     InvalidBitSequenceType(String),
+    /// Invalid bit order type; only `Lsb0` and `Msb0` are supported.
+    #[error("Invalid bit sequence type; bit order type {0} is not supported")]
+    InvalidBitOrderType(String),
 }
 
 #[cfg(test)]
@@ -22,6 +22,11 @@ mod events_type;
 mod filter_events;
 
 pub use decoding::EventsDecodingError;
+pub(crate) use decoding::{
+    bit_store_type,
+    compact_primitive,
+    BitStoreType,
+};
 pub use event_subscription::{
     subscribe,
     subscribe_finalized,
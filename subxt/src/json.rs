@@ -0,0 +1,294 @@
+// Copyright 2019-2022 Parity Technologies (UK) Ltd.
+// This file is part of subxt.
+//
+// subxt is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// subxt is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with subxt.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Dynamically decoding values into JSON, using the type information in the metadata
+//! rather than concrete Rust types.
+
+use crate::{
+    error::BasicError,
+    events::{
+        bit_store_type,
+        compact_primitive,
+        BitStoreType,
+        EventsDecodingError,
+    },
+    metadata::MetadataError,
+};
+use bitvec::{
+    order::{
+        BitOrder,
+        Lsb0,
+        Msb0,
+    },
+    store::BitStore,
+    vec::BitVec,
+};
+use codec::{
+    Compact,
+    Decode,
+};
+use scale_info::{
+    form::PortableForm,
+    Field,
+    PortableRegistry,
+    TypeDef,
+    TypeDefPrimitive,
+};
+use serde_json::{
+    Map as JsonMap,
+    Value as JsonValue,
+};
+
+/// Given a type Id and a type registry, decode the bytes corresponding to that type
+/// from our input into JSON.
+///
+/// Composites with named fields become objects, a composite with a single unnamed
+/// field becomes that field's value, and any other composite, tuple, array or sequence
+/// becomes an array. Variants without fields become their name, and otherwise an
+/// object from their name to their fields. 128 bit integers become strings, whatever
+/// their value, since they do not fit in a JSON number in general.
+pub(crate) fn decode_to_json(
+    type_id: u32,
+    types: &PortableRegistry,
+    input: &mut &[u8],
+) -> Result<JsonValue, BasicError> {
+    let ty = types
+        .resolve(type_id)
+        .ok_or(MetadataError::TypeNotFound(type_id))?;
+
+    fn fields_to_json(
+        fields: &[Field<PortableForm>],
+        types: &PortableRegistry,
+        input: &mut &[u8],
+    ) -> Result<JsonValue, BasicError> {
+        match fields {
+            [] => Ok(JsonValue::Null),
+            [field] if field.name().is_none() => {
+                decode_to_json(field.ty().id(), types, input)
+            }
+            _ if fields.iter().all(|field| field.name().is_some()) => {
+                let mut object = JsonMap::new();
+                for field in fields {
+                    let name = field.name().expect("all fields are named; qed");
+                    let value = decode_to_json(field.ty().id(), types, input)?;
+                    object.insert(name.to_string(), value);
+                }
+                Ok(JsonValue::Object(object))
+            }
+            _ => {
+                fields
+                    .iter()
+                    .map(|field| decode_to_json(field.ty().id(), types, input))
+                    .collect::<Result<_, _>>()
+                    .map(JsonValue::Array)
+            }
+        }
+    }
+
+    fn primitive_to_json<T: Decode + Into<JsonValue>>(
+        input: &mut &[u8],
+    ) -> Result<JsonValue, BasicError> {
+        Ok(T::decode(input)?.into())
+    }
+
+    fn big_to_json<T: ToString>(value: T) -> JsonValue {
+        JsonValue::String(value.to_string())
+    }
+
+    fn bits_to_json<S: BitStore, O: BitOrder>(
+        input: &mut &[u8],
+    ) -> Result<JsonValue, BasicError>
+    where
+        BitVec<S, O>: Decode,
+    {
+        let bits = BitVec::<S, O>::decode(input)?;
+        Ok(JsonValue::Array(
+            bits.iter().map(|bit| JsonValue::Bool(*bit)).collect(),
+        ))
+    }
+
+    match ty.type_def() {
+        TypeDef::Composite(composite) => fields_to_json(composite.fields(), types, input),
+        TypeDef::Variant(variant) => {
+            let variant_index = u8::decode(input)?;
+            let variant = variant
+                .variants()
+                .iter()
+                .find(|v| v.index() == variant_index)
+                .ok_or_else(|| {
+                    BasicError::Other(format!("Variant {} not found", variant_index))
+                })?;
+            if variant.fields().is_empty() {
+                return Ok(JsonValue::String(variant.name().to_string()))
+            }
+            let fields = fields_to_json(variant.fields(), types, input)?;
+            let mut object = JsonMap::new();
+            object.insert(variant.name().to_string(), fields);
+            Ok(JsonValue::Object(object))
+        }
+        TypeDef::Sequence(seq) => {
+            let len = <Compact<u32>>::decode(input)?;
+            (0..len.0)
+                .map(|_| decode_to_json(seq.type_param().id(), types, input))
+                .collect::<Result<_, _>>()
+                .map(JsonValue::Array)
+        }
+        TypeDef::Array(arr) => {
+            (0..arr.len())
+                .map(|_| decode_to_json(arr.type_param().id(), types, input))
+                .collect::<Result<_, _>>()
+                .map(JsonValue::Array)
+        }
+        TypeDef::Tuple(tuple) => {
+            tuple
+                .fields()
+                .iter()
+                .map(|field| decode_to_json(field.id(), types, input))
+                .collect::<Result<_, _>>()
+                .map(JsonValue::Array)
+        }
+        TypeDef::Primitive(primitive) => {
+            match primitive {
+                TypeDefPrimitive::Bool => primitive_to_json::<bool>(input),
+                TypeDefPrimitive::Str => primitive_to_json::<String>(input),
+                TypeDefPrimitive::U8 => primitive_to_json::<u8>(input),
+                TypeDefPrimitive::U16 => primitive_to_json::<u16>(input),
+                TypeDefPrimitive::U32 => primitive_to_json::<u32>(input),
+                TypeDefPrimitive::U64 => primitive_to_json::<u64>(input),
+                TypeDefPrimitive::U128 => Ok(big_to_json(u128::decode(input)?)),
+                TypeDefPrimitive::I8 => primitive_to_json::<i8>(input),
+                TypeDefPrimitive::I16 => primitive_to_json::<i16>(input),
+                TypeDefPrimitive::I32 => primitive_to_json::<i32>(input),
+                TypeDefPrimitive::I64 => primitive_to_json::<i64>(input),
+                TypeDefPrimitive::I128 => Ok(big_to_json(i128::decode(input)?)),
+                prim => {
+                    Err(EventsDecodingError::UnsupportedPrimitive(prim.clone()).into())
+                }
+            }
+        }
+        TypeDef::Compact(compact) => {
+            match compact_primitive(type_id, compact, types)? {
+                TypeDefPrimitive::U8 => Ok(<Compact<u8>>::decode(input)?.0.into()),
+                TypeDefPrimitive::U16 => Ok(<Compact<u16>>::decode(input)?.0.into()),
+                TypeDefPrimitive::U32 => Ok(<Compact<u32>>::decode(input)?.0.into()),
+                TypeDefPrimitive::U64 => Ok(<Compact<u64>>::decode(input)?.0.into()),
+                TypeDefPrimitive::U128 => {
+                    Ok(big_to_json(<Compact<u128>>::decode(input)?.0))
+                }
+                prim => {
+                    Err(EventsDecodingError::InvalidCompactPrimitive(prim.clone()).into())
+                }
+            }
+        }
+        TypeDef::BitSequence(bitseq) => {
+            let bit_store = bit_store_type(type_id, bitseq, types)?;
+            // Unlike when consuming a bit sequence, the bit order matters here since it
+            // determines the order the bits come out in.
+            let bit_order = types
+                .resolve(bitseq.bit_order_type().id())
+                .ok_or(MetadataError::TypeNotFound(type_id))?
+                .path()
+                .ident();
+
+            match (bit_store, bit_order.as_deref()) {
+                (BitStoreType::U8, Some("Lsb0")) => bits_to_json::<u8, Lsb0>(input),
+                (BitStoreType::U8, Some("Msb0")) => bits_to_json::<u8, Msb0>(input),
+                (BitStoreType::U16, Some("Lsb0")) => bits_to_json::<u16, Lsb0>(input),
+                (BitStoreType::U16, Some("Msb0")) => bits_to_json::<u16, Msb0>(input),
+                (BitStoreType::U32, Some("Lsb0")) => bits_to_json::<u32, Lsb0>(input),
+                (BitStoreType::U32, Some("Msb0")) => bits_to_json::<u32, Msb0>(input),
+                (BitStoreType::U64, Some("Lsb0")) => bits_to_json::<u64, Lsb0>(input),
+                (BitStoreType::U64, Some("Msb0")) => bits_to_json::<u64, Msb0>(input),
+                (_, order) => {
+                    Err(
+                        EventsDecodingError::InvalidBitOrderType(format!("{:?}", order))
+                            .into(),
+                    )
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use codec::Encode;
+    use scale_info::TypeInfo;
+
+    type TypeId = scale_info::interner::UntrackedSymbol<std::any::TypeId>;
+
+    /// Build a type registry that knows about the single type provided.
+    fn singleton_type_registry<T: scale_info::TypeInfo + 'static>(
+    ) -> (TypeId, PortableRegistry) {
+        let m = scale_info::MetaType::new::<T>();
+        let mut types = scale_info::Registry::new();
+        let id = types.register_type(&m);
+        let portable_registry: PortableRegistry = types.into();
+
+        (id, portable_registry)
+    }
+
+    #[test]
+    fn decode_to_json() {
+        fn to_json<T: Encode + TypeInfo + 'static>(val: T) -> JsonValue {
+            let (type_id, registry) = singleton_type_registry::<T>();
+            let bytes = val.encode();
+            let cursor = &mut &*bytes;
+            let json = super::decode_to_json(type_id.id(), &registry, cursor).unwrap();
+            assert_eq!(cursor.len(), 0);
+            json
+        }
+
+        #[derive(Encode, TypeInfo)]
+        struct Named {
+            id: u32,
+            name: String,
+        }
+        #[derive(Encode, TypeInfo)]
+        struct Newtype(#[codec(compact)] u64);
+        #[derive(Encode, TypeInfo)]
+        enum Enum {
+            A,
+            B(u8, bool),
+        }
+
+        assert_eq!(
+            to_json(Named {
+                id: 1,
+                name: "str".to_string()
+            }),
+            serde_json::json!({ "id": 1, "name": "str" })
+        );
+        assert_eq!(to_json(Newtype(2)), serde_json::json!(2));
+        assert_eq!(to_json(Enum::A), serde_json::json!("A"));
+        assert_eq!(
+            to_json(Enum::B(1, true)),
+            serde_json::json!({ "B": [1, true] })
+        );
+        assert_eq!(
+            to_json((vec![1u8, 2], [true])),
+            serde_json::json!([[1, 2], [true]])
+        );
+        // 128 bit integers are always strings, so the JSON type does not depend on the value.
+        assert_eq!(to_json(-1i128), serde_json::json!("-1"));
+        assert_eq!(to_json(u128::MAX), serde_json::json!(u128::MAX.to_string()));
+        assert_eq!(
+            to_json(bitvec::bitvec![u8, bitvec::order::Msb0; 1, 0, 1]),
+            serde_json::json!([true, false, true])
+        );
+    }
+}
@@ -41,10 +41,15 @@
 #![allow(clippy::type_complexity)]
 
 pub use frame_metadata::StorageHasher;
+pub use subxt_codegen::{
+    check_magic_number,
+    InvalidMagic,
+};
 pub use subxt_macro::subxt;
 
 pub use bitvec;
 pub use codec;
+pub use serde_json;
 pub use sp_core;
 pub use sp_runtime;
 
@@ -61,6 +66,7 @@ mod config;
 mod error;
 pub mod events;
 pub mod extrinsic;
+mod json;
 mod metadata;
 pub mod rpc;
 pub mod storage;
@@ -99,7 +105,9 @@ pub use crate::{
         SubstrateExtrinsicParamsBuilder,
     },
     metadata::{
+        validate_registry,
         ErrorMetadata,
+        InvalidMetadataError,
         Metadata,
         MetadataError,
         PalletMetadata,
@@ -15,11 +15,20 @@
 // along with subxt.  If not, see <http://www.gnu.org/licenses/>.
 
 use std::{
-    collections::HashMap,
+    any::Any,
+    collections::{
+        HashMap,
+        HashSet,
+    },
     convert::TryFrom,
+    sync::Arc,
 };
 
-use codec::Error as CodecError;
+use codec::{
+    Decode,
+    DecodeAll,
+    Error as CodecError,
+};
 
 use frame_metadata::{
     PalletConstantMetadata,
@@ -30,12 +39,20 @@ use frame_metadata::{
     META_RESERVED,
 };
 
-use crate::Call;
+use crate::{
+    json::decode_to_json,
+    BasicError,
+    Call,
+};
+use once_cell::sync::OnceCell;
 use scale_info::{
     form::PortableForm,
+    PortableRegistry,
     Type,
+    TypeDef,
     Variant,
 };
+use subxt_codegen::check_magic_number;
 
 /// Metadata error.
 #[derive(Debug, thiserror::Error)]
@@ -67,9 +84,26 @@ pub enum MetadataError {
     /// Failure to decode constant value.
     #[error("Failed to decode constant value: {0}")]
     ConstantValueError(CodecError),
+    /// Failure to decode a constant value into the type it was generated against.
+    #[error("Failed to decode constant {pallet}::{constant} as {type_path} from {len} bytes: {error}")]
+    ConstantDecodeError {
+        /// Name of the pallet the constant belongs to.
+        pallet: String,
+        /// Name of the constant.
+        constant: &'static str,
+        /// Path of the type the constant was expected to decode into.
+        type_path: String,
+        /// Length of the raw constant value.
+        len: usize,
+        /// The underlying decode error.
+        error: CodecError,
+    },
     /// Constant is not in metadata.
     #[error("Constant {0} not found")]
     ConstantNotFound(&'static str),
+    /// Constant was previously decoded and cached as a different type.
+    #[error("Constant {0} was cached as a different type")]
+    ConstantTypeMismatch(&'static str),
     /// Type is not in metadata.
     #[error("Type {0} missing from type registry")]
     TypeNotFound(u32),
@@ -92,6 +126,31 @@ impl Metadata {
             .ok_or_else(|| MetadataError::PalletNotFound(name.to_string()))
     }
 
+    /// Decode metadata from a hex string, such as the one returned by the
+    /// `state_getMetadata` RPC call. The `0x` prefix is optional.
+    pub fn from_hex(hex: &str) -> Result<Self, InvalidMetadataError> {
+        let bytes = hex::decode(hex.strip_prefix("0x").unwrap_or(hex))
+            .map_err(InvalidMetadataError::InvalidHex)?;
+        check_magic_number(&bytes).map_err(|_| InvalidMetadataError::InvalidPrefix)?;
+        let metadata = RuntimeMetadataPrefixed::decode(&mut &bytes[..])
+            .map_err(InvalidMetadataError::Decode)?;
+        Self::try_from(metadata)
+    }
+
+    /// Returns the names of all pallets, in the order they appear in the metadata.
+    pub fn pallet_names(&self) -> Vec<&str> {
+        self.metadata
+            .pallets
+            .iter()
+            .map(|pallet| pallet.name.as_str())
+            .collect()
+    }
+
+    /// Returns true if the metadata contains a pallet with the given name.
+    pub fn contains_pallet(&self, name: &str) -> bool {
+        self.pallets.contains_key(name)
+    }
+
     /// Returns the metadata for the event at the given pallet and event indices.
     pub fn event(
         &self,
@@ -118,6 +177,35 @@ impl Metadata {
         Ok(error)
     }
 
+    /// Decode a constant's value as JSON, using the type information in the metadata
+    /// rather than a concrete Rust type.
+    pub fn constant_json(
+        &self,
+        pallet: &'static str,
+        constant: &'static str,
+    ) -> Result<serde_json::Value, BasicError> {
+        let value = &self.pallet(pallet)?.constant(constant)?;
+        let type_id = value.ty.id();
+        let mut bytes = &value.value[..];
+        let json = decode_to_json(type_id, &self.metadata.types, &mut bytes)?;
+        // Leftover bytes mean the value was decoded as the wrong type. Decoding `()`
+        // consumes nothing, so `decode_all` fails on them with the usual codec error.
+        <()>::decode_all(&mut bytes).map_err(|error| {
+            let type_path = match self.resolve_type(type_id) {
+                Some(ty) if !ty.path().is_empty() => ty.path().to_string(),
+                _ => format!("type {}", type_id),
+            };
+            MetadataError::ConstantDecodeError {
+                pallet: pallet.to_string(),
+                constant,
+                type_path,
+                len: value.value.len(),
+                error,
+            }
+        })?;
+        Ok(json)
+    }
+
     /// Resolve a type definition.
     pub fn resolve_type(&self, id: u32) -> Option<&Type<PortableForm>> {
         self.metadata.types.resolve(id)
@@ -137,6 +225,7 @@ pub struct PalletMetadata {
     calls: HashMap<String, u8>,
     storage: HashMap<String, StorageEntryMetadata<PortableForm>>,
     constants: HashMap<String, PalletConstantMetadata<PortableForm>>,
+    decoded_constants: Arc<HashMap<String, OnceCell<Box<dyn Any + Send + Sync>>>>,
 }
 
 impl PalletMetadata {
@@ -182,6 +271,65 @@ impl PalletMetadata {
             .get(key)
             .ok_or(MetadataError::ConstantNotFound(key))
     }
+
+    /// Get a constant's raw SCALE encoded value, along with the id of its type in the
+    /// metadata's type registry, for decoding it dynamically.
+    pub fn raw_constant(
+        &self,
+        key: &'static str,
+    ) -> Result<(Vec<u8>, u32), MetadataError> {
+        let constant = self.constant(key)?;
+        Ok((constant.value.clone(), constant.ty.id()))
+    }
+
+    /// Decode a constant's value as `V`.
+    ///
+    /// Fails with [`MetadataError::ConstantDecodeError`] if the value does not decode
+    /// as `V`, or if decoding leaves bytes behind.
+    pub fn decode_constant<V: Decode>(
+        &self,
+        key: &'static str,
+    ) -> Result<V, MetadataError> {
+        let constant = self.constant(key)?;
+        // `decode_all` rejects leftover bytes, which mean the value was decoded as the
+        // wrong type.
+        V::decode_all(&mut &constant.value[..]).map_err(|error| {
+            MetadataError::ConstantDecodeError {
+                pallet: self.name.clone(),
+                constant: key,
+                type_path: std::any::type_name::<V>().to_string(),
+                len: constant.value.len(),
+                error,
+            }
+        })
+    }
+
+    /// Decode a constant's value as `V`, caching the result.
+    ///
+    /// Constants never change for a given metadata, so only the first call for a
+    /// constant decodes it; later calls return a reference to the cached value.
+    ///
+    /// The cache holds a single value per constant, of whichever type it was first
+    /// read as. Reading it as any other type from then on fails with
+    /// [`MetadataError::ConstantTypeMismatch`]; use [`Self::decode_constant`] to read
+    /// a constant as more than one type.
+    pub fn cached_constant<V>(&self, key: &'static str) -> Result<&V, MetadataError>
+    where
+        V: Decode + Send + Sync + 'static,
+    {
+        let cell = self
+            .decoded_constants
+            .get(key)
+            .ok_or(MetadataError::ConstantNotFound(key))?;
+        let value = cell.get_or_try_init(|| {
+            let value = self.decode_constant::<V>(key)?;
+            let boxed: Box<dyn Any + Send + Sync> = Box::new(value);
+            Ok::<_, MetadataError>(boxed)
+        })?;
+        value
+            .downcast_ref::<V>()
+            .ok_or(MetadataError::ConstantTypeMismatch(key))
+    }
 }
 
 /// Metadata for specific events.
@@ -234,18 +382,107 @@ impl ErrorMetadata {
     }
 }
 
+/// Error validating or decoding runtime metadata.
 #[derive(Debug, thiserror::Error)]
 pub enum InvalidMetadataError {
+    /// Metadata hex string could not be decoded.
+    #[error("Invalid hex: {0}")]
+    InvalidHex(hex::FromHexError),
+    /// Metadata bytes could not be decoded.
+    #[error("Failed to decode metadata: {0}")]
+    Decode(CodecError),
+    /// Metadata does not start with the expected magic number.
     #[error("Invalid prefix")]
     InvalidPrefix,
+    /// Metadata version is not supported.
     #[error("Invalid version")]
     InvalidVersion,
+    /// Type is not in metadata.
     #[error("Type {0} missing from type registry")]
     MissingType(u32),
+    /// Type id appears more than once in the type registry.
+    #[error("Type {0} appears more than once in type registry")]
+    DuplicateType(u32),
+    /// Type id does not match its position in the type registry.
+    #[error("Type at position {position} in type registry has id {id}")]
+    MisplacedType {
+        /// Position of the type in the registry.
+        position: usize,
+        /// Id the type claims to have.
+        id: u32,
+    },
+    /// Type was expected to be a variant.
     #[error("Type {0} was not a variant/enum type")]
     TypeDefNotVariant(u32),
 }
 
+/// Check that a type registry is well formed: every type id appears once and matches
+/// its position, and every type id referred to resolves to a type in the registry.
+///
+/// Types are looked up by position, so a malformed registry would otherwise resolve
+/// ids to the wrong types or fail somewhere far away from the actual problem.
+pub fn validate_registry(
+    registry: &PortableRegistry,
+) -> Result<(), InvalidMetadataError> {
+    // Look for duplicates first: with one, some other id is bound to be out of place
+    // too, which would be a less helpful error to report.
+    let mut seen = HashSet::new();
+    for ty in registry.types() {
+        if !seen.insert(ty.id()) {
+            return Err(InvalidMetadataError::DuplicateType(ty.id()))
+        }
+    }
+    for (position, ty) in registry.types().iter().enumerate() {
+        if ty.id() as usize != position {
+            return Err(InvalidMetadataError::MisplacedType {
+                position,
+                id: ty.id(),
+            })
+        }
+    }
+
+    let check = |id: u32| {
+        registry
+            .resolve(id)
+            .map(|_| ())
+            .ok_or(InvalidMetadataError::MissingType(id))
+    };
+    for ty in registry.types() {
+        let ty = ty.ty();
+        for param in ty.type_params() {
+            if let Some(param_ty) = param.ty() {
+                check(param_ty.id())?;
+            }
+        }
+        match ty.type_def() {
+            TypeDef::Composite(composite) => {
+                for field in composite.fields() {
+                    check(field.ty().id())?;
+                }
+            }
+            TypeDef::Variant(variant) => {
+                for field in variant.variants().iter().flat_map(|v| v.fields()) {
+                    check(field.ty().id())?;
+                }
+            }
+            TypeDef::Sequence(seq) => check(seq.type_param().id())?,
+            TypeDef::Array(arr) => check(arr.type_param().id())?,
+            TypeDef::Tuple(tuple) => {
+                for field in tuple.fields() {
+                    check(field.id())?;
+                }
+            }
+            TypeDef::Primitive(_) => {}
+            TypeDef::Compact(compact) => check(compact.type_param().id())?,
+            TypeDef::BitSequence(bitseq) => {
+                check(bitseq.bit_store_type().id())?;
+                check(bitseq.bit_order_type().id())?;
+            }
+        }
+    }
+    Ok(())
+}
+
 impl TryFrom<RuntimeMetadataPrefixed> for Metadata {
     type Error = InvalidMetadataError;
 
@@ -257,13 +494,14 @@ impl TryFrom<RuntimeMetadataPrefixed> for Metadata {
             RuntimeMetadata::V14(meta) => meta,
             _ => return Err(InvalidMetadataError::InvalidVersion),
         };
+        validate_registry(&metadata.types)?;
 
         let get_type_def_variant = |type_id: u32| {
             let ty = metadata
                 .types
                 .resolve(type_id)
                 .ok_or(InvalidMetadataError::MissingType(type_id))?;
-            if let scale_info::TypeDef::Variant(var) = ty.type_def() {
+            if let TypeDef::Variant(var) = ty.type_def() {
                 Ok(var)
             } else {
                 Err(InvalidMetadataError::TypeDefNotVariant(type_id))
@@ -297,12 +535,19 @@ impl TryFrom<RuntimeMetadataPrefixed> for Metadata {
                     .map(|constant| (constant.name.clone(), constant.clone()))
                     .collect();
 
+                let decoded_constants = pallet
+                    .constants
+                    .iter()
+                    .map(|constant| (constant.name.clone(), OnceCell::new()))
+                    .collect();
+
                 let pallet_metadata = PalletMetadata {
                     index: pallet.index,
                     name: pallet.name.to_string(),
                     calls,
                     storage,
                     constants,
+                    decoded_constants: Arc::new(decoded_constants),
                 };
 
                 Ok((pallet.name.to_string(), pallet_metadata))
@@ -367,3 +612,325 @@ impl TryFrom<RuntimeMetadataPrefixed> for Metadata {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use codec::{
+        Compact,
+        Encode,
+    };
+    use frame_metadata::{
+        ExtrinsicMetadata,
+        PalletMetadata as FramePalletMetadata,
+        RuntimeMetadataV14,
+    };
+    use scale_info::{
+        meta_type,
+        Registry,
+    };
+    use std::sync::atomic::{
+        AtomicUsize,
+        Ordering,
+    };
+
+    fn runtime_metadata_with_pallets(names: &[&'static str]) -> RuntimeMetadataPrefixed {
+        let pallets = names
+            .iter()
+            .enumerate()
+            .map(|(index, &name)| {
+                FramePalletMetadata {
+                    name,
+                    storage: None,
+                    calls: None,
+                    event: None,
+                    constants: Vec::new(),
+                    error: None,
+                    index: index as u8,
+                }
+            })
+            .collect();
+        let extrinsic = ExtrinsicMetadata {
+            ty: meta_type::<()>(),
+            version: 4,
+            signed_extensions: Vec::new(),
+        };
+        RuntimeMetadataV14::new(pallets, extrinsic, meta_type::<()>()).into()
+    }
+
+    fn metadata_with_pallets(names: &[&'static str]) -> Metadata {
+        Metadata::try_from(runtime_metadata_with_pallets(names)).unwrap()
+    }
+
+    fn pallet_with_constant(value: Vec<u8>) -> PalletMetadata {
+        let constant = PalletConstantMetadata::<PortableForm> {
+            name: "ExistentialDeposit".to_string(),
+            ty: Registry::new().register_type(&meta_type::<u128>()),
+            value,
+            docs: Vec::new(),
+        };
+        PalletMetadata {
+            index: 0,
+            name: "Balances".to_string(),
+            calls: HashMap::new(),
+            storage: HashMap::new(),
+            decoded_constants: Arc::new(
+                [(constant.name.clone(), OnceCell::new())]
+                    .into_iter()
+                    .collect(),
+            ),
+            constants: [(constant.name.clone(), constant)].into_iter().collect(),
+        }
+    }
+
+    #[test]
+    fn decode_constant() {
+        let pallet = pallet_with_constant(500u128.encode());
+        let value: u128 = pallet.decode_constant("ExistentialDeposit").unwrap();
+        assert_eq!(value, 500);
+    }
+
+    #[test]
+    fn constant_json() {
+        let constant = |name, value| {
+            PalletConstantMetadata {
+                name,
+                ty: meta_type::<u128>(),
+                value,
+                docs: Vec::new(),
+            }
+        };
+        let pallet = FramePalletMetadata {
+            name: "Balances",
+            storage: None,
+            calls: None,
+            event: None,
+            constants: vec![
+                constant("ExistentialDeposit", 500u128.encode()),
+                constant("TotalIssuance", u128::MAX.encode()),
+                constant("TrailingBytes", (500u128, 1u8).encode()),
+            ],
+            error: None,
+            index: 0,
+        };
+        let extrinsic = ExtrinsicMetadata {
+            ty: meta_type::<()>(),
+            version: 4,
+            signed_extensions: Vec::new(),
+        };
+        let runtime_metadata: RuntimeMetadataPrefixed =
+            RuntimeMetadataV14::new(vec![pallet], extrinsic, meta_type::<()>()).into();
+        let metadata = Metadata::try_from(runtime_metadata).unwrap();
+
+        assert_eq!(
+            metadata
+                .constant_json("Balances", "ExistentialDeposit")
+                .unwrap(),
+            serde_json::json!("500")
+        );
+        assert_eq!(
+            metadata.constant_json("Balances", "TotalIssuance").unwrap(),
+            serde_json::json!(u128::MAX.to_string())
+        );
+        // Types without a path, like `u128`, are reported by their id.
+        let type_id = metadata
+            .pallet("Balances")
+            .unwrap()
+            .constant("TrailingBytes")
+            .unwrap()
+            .ty
+            .id();
+        assert!(matches!(
+            metadata.constant_json("Balances", "TrailingBytes"),
+            Err(crate::GenericError::Metadata(
+                MetadataError::ConstantDecodeError { type_path, len: 17, .. }
+            )) if type_path == format!("type {}", type_id)
+        ));
+    }
+
+    #[test]
+    fn raw_constant() {
+        let pallet = pallet_with_constant(500u128.encode());
+        let constant = pallet.constant("ExistentialDeposit").unwrap();
+        let (bytes, type_id) = pallet.raw_constant("ExistentialDeposit").unwrap();
+        assert_eq!(bytes, constant.value);
+        assert_eq!(type_id, constant.ty.id());
+
+        assert!(matches!(
+            pallet.raw_constant("MaxLocks"),
+            Err(MetadataError::ConstantNotFound("MaxLocks"))
+        ));
+    }
+
+    #[test]
+    fn decode_constant_error_names_pallet_and_constant() {
+        let pallet = pallet_with_constant(vec![1, 2, 3]);
+        let err = pallet
+            .decode_constant::<u128>("ExistentialDeposit")
+            .unwrap_err();
+        let message = err.to_string();
+        assert!(message.contains("Balances"), "{}", message);
+        assert!(message.contains("ExistentialDeposit"), "{}", message);
+        assert!(message.contains("u128"), "{}", message);
+    }
+
+    #[test]
+    fn decode_constant_rejects_trailing_bytes() {
+        let pallet = pallet_with_constant((500u128, 1u8).encode());
+        let err = pallet
+            .decode_constant::<u128>("ExistentialDeposit")
+            .unwrap_err();
+        assert!(
+            matches!(err, MetadataError::ConstantDecodeError { len: 17, .. }),
+            "{:?}",
+            err
+        );
+    }
+
+    #[test]
+    fn cached_constant_is_decoded_once() {
+        static DECODES: AtomicUsize = AtomicUsize::new(0);
+
+        #[derive(Debug, PartialEq)]
+        struct Counted(u128);
+
+        impl Decode for Counted {
+            fn decode<I: codec::Input>(input: &mut I) -> Result<Self, CodecError> {
+                DECODES.fetch_add(1, Ordering::SeqCst);
+                Ok(Counted(u128::decode(input)?))
+            }
+        }
+
+        let pallet = pallet_with_constant(500u128.encode());
+        for _ in 0..3 {
+            let value: &Counted = pallet.cached_constant("ExistentialDeposit").unwrap();
+            assert_eq!(value, &Counted(500));
+        }
+        assert_eq!(DECODES.load(Ordering::SeqCst), 1);
+
+        let err = pallet
+            .cached_constant::<u128>("ExistentialDeposit")
+            .unwrap_err();
+        assert!(
+            matches!(
+                err,
+                MetadataError::ConstantTypeMismatch("ExistentialDeposit")
+            ),
+            "{:?}",
+            err
+        );
+    }
+
+    #[test]
+    fn cached_constant_is_not_cloned() {
+        let pallet = pallet_with_constant(vec![1u32; 1024].encode());
+        let first: &Vec<u32> = pallet.cached_constant("ExistentialDeposit").unwrap();
+        let second: &Vec<u32> = pallet.cached_constant("ExistentialDeposit").unwrap();
+        assert_eq!(first.len(), 1024);
+        // Both calls hand out the same allocation.
+        assert!(std::ptr::eq(first.as_ptr(), second.as_ptr()));
+    }
+
+    /// Register `u8` and `Vec<u8>` and re-encode the registry after letting `f` mess
+    /// with the (id, type) entries.
+    fn tampered_registry(
+        f: impl FnOnce(&mut Vec<(Compact<u32>, Type<PortableForm>)>),
+    ) -> PortableRegistry {
+        let mut registry = Registry::new();
+        registry.register_type(&meta_type::<Vec<u8>>());
+        let registry: PortableRegistry = registry.into();
+        let mut types =
+            Vec::<(Compact<u32>, Type<PortableForm>)>::decode(&mut &*registry.encode())
+                .unwrap();
+        f(&mut types);
+        PortableRegistry::decode(&mut &*types.encode()).unwrap()
+    }
+
+    #[test]
+    fn validate_registry() {
+        let registry = tampered_registry(|_| {});
+        assert!(super::validate_registry(&registry).is_ok());
+
+        // `Vec<u8>` now refers to a type which no longer exists.
+        let registry = tampered_registry(|types| types.truncate(1));
+        assert!(matches!(
+            super::validate_registry(&registry),
+            Err(InvalidMetadataError::MissingType(1))
+        ));
+
+        let registry = tampered_registry(|types| types[1].0 = Compact(0));
+        assert!(matches!(
+            super::validate_registry(&registry),
+            Err(InvalidMetadataError::DuplicateType(0))
+        ));
+
+        // The duplicate is reported even though it comes after a misplaced id.
+        let registry = tampered_registry(|types| types[0].0 = Compact(1));
+        assert!(matches!(
+            super::validate_registry(&registry),
+            Err(InvalidMetadataError::DuplicateType(1))
+        ));
+
+        let registry = tampered_registry(|types| types[1].0 = Compact(5));
+        assert!(matches!(
+            super::validate_registry(&registry),
+            Err(InvalidMetadataError::MisplacedType { position: 1, id: 5 })
+        ));
+    }
+
+    #[test]
+    fn pallet_names_in_metadata_order() {
+        let metadata = metadata_with_pallets(&["System", "Timestamp", "Balances"]);
+        assert_eq!(
+            metadata.pallet_names(),
+            vec!["System", "Timestamp", "Balances"]
+        );
+    }
+
+    #[test]
+    fn contains_pallet() {
+        let metadata = metadata_with_pallets(&["System", "Balances"]);
+        assert!(metadata.contains_pallet("Balances"));
+        assert!(!metadata.contains_pallet("Staking"));
+    }
+
+    #[test]
+    fn from_hex() {
+        let bytes = runtime_metadata_with_pallets(&["System", "Balances"]).encode();
+        for hex in [hex::encode(&bytes), format!("0x{}", hex::encode(&bytes))] {
+            let metadata = Metadata::from_hex(&hex).unwrap();
+            assert_eq!(metadata.pallet_names(), vec!["System", "Balances"]);
+        }
+    }
+
+    #[test]
+    fn from_hex_rejects_malformed_input() {
+        let mut bytes = runtime_metadata_with_pallets(&["System"]).encode();
+
+        let err = Metadata::from_hex("0xnot hex").unwrap_err();
+        assert!(
+            matches!(err, InvalidMetadataError::InvalidHex(_)),
+            "{:?}",
+            err
+        );
+
+        let err = Metadata::from_hex(&hex::encode(&bytes[..2])).unwrap_err();
+        assert!(
+            matches!(err, InvalidMetadataError::InvalidPrefix),
+            "{:?}",
+            err
+        );
+
+        let err =
+            Metadata::from_hex(&hex::encode(&bytes[..bytes.len() / 2])).unwrap_err();
+        assert!(matches!(err, InvalidMetadataError::Decode(_)), "{:?}", err);
+
+        bytes[0] = b'x';
+        let err = Metadata::from_hex(&hex::encode(&bytes)).unwrap_err();
+        assert!(
+            matches!(err, InvalidMetadataError::InvalidPrefix),
+            "{:?}",
+            err
+        );
+    }
+}
@@ -27,9 +27,11 @@ use std::{
 };
 
 use crate::{
+    check_magic_number,
     error::BasicError,
     storage::StorageKeyPrefix,
     Config,
+    InvalidMetadataError,
     Metadata,
     PhantomDataSendSync,
 };
@@ -316,6 +318,7 @@ impl<T: Config> Rpc<T> {
             .client
             .request("state_getMetadata", rpc_params![])
             .await?;
+        check_magic_number(&bytes).map_err(|_| InvalidMetadataError::InvalidPrefix)?;
         let meta: RuntimeMetadataPrefixed = Decode::decode(&mut &bytes[..])?;
         let metadata: Metadata = meta.try_into()?;
         Ok(metadata)
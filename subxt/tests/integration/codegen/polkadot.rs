@@ -747,75 +747,209 @@ pub mod api {
         }
         pub mod constants {
             use super::runtime_types;
-            pub struct ConstantsApi<'a, T: ::subxt::Config> {
-                client: &'a ::subxt::Client<T>,
+            pub type BlockWeightsTy = runtime_types::frame_system::limits::BlockWeights;
+            pub type BlockLengthTy = runtime_types::frame_system::limits::BlockLength;
+            pub type BlockHashCountTy = ::core::primitive::u32;
+            pub type DbWeightTy = runtime_types::frame_support::weights::RuntimeDbWeight;
+            pub type VersionTy = runtime_types::sp_version::RuntimeVersion;
+            pub type Ss58PrefixTy = ::core::primitive::u16;
+            pub struct ConstantsApi<'a, T> {
+                metadata: &'a ::subxt::Metadata,
+                marker: ::core::marker::PhantomData<T>,
             }
             impl<'a, T: ::subxt::Config> ConstantsApi<'a, T> {
                 pub fn new(client: &'a ::subxt::Client<T>) -> Self {
-                    Self { client }
+                    Self::from_metadata(client.metadata())
+                }
+            }
+            impl<'a, T> ConstantsApi<'a, T> {
+                #[doc = r" Read constants straight from `metadata`, without a client to fetch it with."]
+                #[doc = r""]
+                #[doc = r" `T` is only needed by [`Self::new`], so any type will do here."]
+                pub fn from_metadata(metadata: &'a ::subxt::Metadata) -> Self {
+                    Self {
+                        metadata,
+                        marker: ::core::marker::PhantomData,
+                    }
                 }
+                #[doc = " Block & extrinsics weights: base values and limits."]
                 pub fn block_weights(
                     &self,
+                ) -> ::core::result::Result<BlockWeightsTy, ::subxt::BasicError>
+                {
+                    let pallet = self.metadata.pallet("System")?;
+                    let value =
+                        pallet.decode_constant::<BlockWeightsTy>("BlockWeights")?;
+                    Ok(value)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn block_weights_raw(
+                    &self,
                 ) -> ::core::result::Result<
-                    runtime_types::frame_system::limits::BlockWeights,
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
                     ::subxt::BasicError,
                 > {
-                    let pallet = self.client.metadata().pallet("System")?;
-                    let constant = pallet.constant("BlockWeights")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("System")?;
+                    let value = pallet.raw_constant("BlockWeights")?;
                     Ok(value)
                 }
+                #[doc = " The maximum length of a block (in bytes)."]
                 pub fn block_length(
                     &self,
+                ) -> ::core::result::Result<BlockLengthTy, ::subxt::BasicError>
+                {
+                    let pallet = self.metadata.pallet("System")?;
+                    let value = pallet.decode_constant::<BlockLengthTy>("BlockLength")?;
+                    Ok(value)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn block_length_raw(
+                    &self,
                 ) -> ::core::result::Result<
-                    runtime_types::frame_system::limits::BlockLength,
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
                     ::subxt::BasicError,
                 > {
-                    let pallet = self.client.metadata().pallet("System")?;
-                    let constant = pallet.constant("BlockLength")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("System")?;
+                    let value = pallet.raw_constant("BlockLength")?;
                     Ok(value)
                 }
+                #[doc = " Maximum number of block number to block hash mappings to keep (oldest pruned first)."]
                 pub fn block_hash_count(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u32, ::subxt::BasicError>
+                ) -> ::core::result::Result<BlockHashCountTy, ::subxt::BasicError>
                 {
-                    let pallet = self.client.metadata().pallet("System")?;
-                    let constant = pallet.constant("BlockHashCount")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("System")?;
+                    let value =
+                        pallet.decode_constant::<BlockHashCountTy>("BlockHashCount")?;
+                    Ok(value)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn block_hash_count_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("System")?;
+                    let value = pallet.raw_constant("BlockHashCount")?;
                     Ok(value)
                 }
+                #[doc = " The weight of runtime database operations the runtime can invoke."]
                 pub fn db_weight(
                     &self,
+                ) -> ::core::result::Result<DbWeightTy, ::subxt::BasicError>
+                {
+                    let pallet = self.metadata.pallet("System")?;
+                    let value = pallet.decode_constant::<DbWeightTy>("DbWeight")?;
+                    Ok(value)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn db_weight_raw(
+                    &self,
                 ) -> ::core::result::Result<
-                    runtime_types::frame_support::weights::RuntimeDbWeight,
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
                     ::subxt::BasicError,
                 > {
-                    let pallet = self.client.metadata().pallet("System")?;
-                    let constant = pallet.constant("DbWeight")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("System")?;
+                    let value = pallet.raw_constant("DbWeight")?;
                     Ok(value)
                 }
+                #[doc = " Get the chain's current version."]
                 pub fn version(
                     &self,
+                ) -> ::core::result::Result<VersionTy, ::subxt::BasicError>
+                {
+                    let pallet = self.metadata.pallet("System")?;
+                    let value = pallet.decode_constant::<VersionTy>("Version")?;
+                    Ok(value)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn version_raw(
+                    &self,
                 ) -> ::core::result::Result<
-                    runtime_types::sp_version::RuntimeVersion,
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
                     ::subxt::BasicError,
                 > {
-                    let pallet = self.client.metadata().pallet("System")?;
-                    let constant = pallet.constant("Version")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("System")?;
+                    let value = pallet.raw_constant("Version")?;
                     Ok(value)
                 }
+                #[doc = " The designated SS85 prefix of this chain."]
+                #[doc = ""]
+                #[doc = " This replaces the \"ss58Format\" property declared in the chain spec. Reason is"]
+                #[doc = " that the runtime should know about the prefix in order to make use of it as"]
+                #[doc = " an identifier of the chain."]
                 pub fn ss58_prefix(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u16, ::subxt::BasicError>
+                ) -> ::core::result::Result<Ss58PrefixTy, ::subxt::BasicError>
                 {
-                    let pallet = self.client.metadata().pallet("System")?;
-                    let constant = pallet.constant("SS58Prefix")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("System")?;
+                    let value = pallet.decode_constant::<Ss58PrefixTy>("SS58Prefix")?;
+                    Ok(value)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn ss58_prefix_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("System")?;
+                    let value = pallet.raw_constant("SS58Prefix")?;
                     Ok(value)
                 }
+                #[doc = r" Returns the name and raw SCALE encoded value of every constant in this pallet."]
+                pub fn all(
+                    &self,
+                ) -> ::core::result::Result<
+                    ::std::vec::Vec<(
+                        &'static str,
+                        ::std::vec::Vec<::core::primitive::u8>,
+                    )>,
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("System")?;
+                    let mut constants = ::std::vec::Vec::new();
+                    constants.push((
+                        "BlockWeights",
+                        pallet.constant("BlockWeights")?.value.clone(),
+                    ));
+                    constants.push((
+                        "BlockLength",
+                        pallet.constant("BlockLength")?.value.clone(),
+                    ));
+                    constants.push((
+                        "BlockHashCount",
+                        pallet.constant("BlockHashCount")?.value.clone(),
+                    ));
+                    constants
+                        .push(("DbWeight", pallet.constant("DbWeight")?.value.clone()));
+                    constants
+                        .push(("Version", pallet.constant("Version")?.value.clone()));
+                    constants.push((
+                        "SS58Prefix",
+                        pallet.constant("SS58Prefix")?.value.clone(),
+                    ));
+                    Ok(constants)
+                }
             }
         }
     }
@@ -1210,31 +1344,100 @@ pub mod api {
         }
         pub mod constants {
             use super::runtime_types;
-            pub struct ConstantsApi<'a, T: ::subxt::Config> {
-                client: &'a ::subxt::Client<T>,
+            pub type MaximumWeightTy = ::core::primitive::u64;
+            pub type MaxScheduledPerBlockTy = ::core::primitive::u32;
+            pub struct ConstantsApi<'a, T> {
+                metadata: &'a ::subxt::Metadata,
+                marker: ::core::marker::PhantomData<T>,
             }
             impl<'a, T: ::subxt::Config> ConstantsApi<'a, T> {
                 pub fn new(client: &'a ::subxt::Client<T>) -> Self {
-                    Self { client }
+                    Self::from_metadata(client.metadata())
+                }
+            }
+            impl<'a, T> ConstantsApi<'a, T> {
+                #[doc = r" Read constants straight from `metadata`, without a client to fetch it with."]
+                #[doc = r""]
+                #[doc = r" `T` is only needed by [`Self::new`], so any type will do here."]
+                pub fn from_metadata(metadata: &'a ::subxt::Metadata) -> Self {
+                    Self {
+                        metadata,
+                        marker: ::core::marker::PhantomData,
+                    }
                 }
+                #[doc = " The maximum weight that may be scheduled per block for any dispatchables of less"]
+                #[doc = " priority than `schedule::HARD_DEADLINE`."]
                 pub fn maximum_weight(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u64, ::subxt::BasicError>
+                ) -> ::core::result::Result<MaximumWeightTy, ::subxt::BasicError>
                 {
-                    let pallet = self.client.metadata().pallet("Scheduler")?;
-                    let constant = pallet.constant("MaximumWeight")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("Scheduler")?;
+                    let value =
+                        pallet.decode_constant::<MaximumWeightTy>("MaximumWeight")?;
+                    Ok(value)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn maximum_weight_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Scheduler")?;
+                    let value = pallet.raw_constant("MaximumWeight")?;
                     Ok(value)
                 }
+                #[doc = " The maximum number of scheduled calls in the queue for a single block."]
+                #[doc = " Not strictly enforced, but used for weight estimation."]
                 pub fn max_scheduled_per_block(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u32, ::subxt::BasicError>
+                ) -> ::core::result::Result<MaxScheduledPerBlockTy, ::subxt::BasicError>
                 {
-                    let pallet = self.client.metadata().pallet("Scheduler")?;
-                    let constant = pallet.constant("MaxScheduledPerBlock")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("Scheduler")?;
+                    let value = pallet.decode_constant::<MaxScheduledPerBlockTy>(
+                        "MaxScheduledPerBlock",
+                    )?;
+                    Ok(value)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn max_scheduled_per_block_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Scheduler")?;
+                    let value = pallet.raw_constant("MaxScheduledPerBlock")?;
                     Ok(value)
                 }
+                #[doc = r" Returns the name and raw SCALE encoded value of every constant in this pallet."]
+                pub fn all(
+                    &self,
+                ) -> ::core::result::Result<
+                    ::std::vec::Vec<(
+                        &'static str,
+                        ::std::vec::Vec<::core::primitive::u8>,
+                    )>,
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Scheduler")?;
+                    let mut constants = ::std::vec::Vec::new();
+                    constants.push((
+                        "MaximumWeight",
+                        pallet.constant("MaximumWeight")?.value.clone(),
+                    ));
+                    constants.push((
+                        "MaxScheduledPerBlock",
+                        pallet.constant("MaxScheduledPerBlock")?.value.clone(),
+                    ));
+                    Ok(constants)
+                }
             }
         }
     }
@@ -1910,40 +2113,132 @@ pub mod api {
         }
         pub mod constants {
             use super::runtime_types;
-            pub struct ConstantsApi<'a, T: ::subxt::Config> {
-                client: &'a ::subxt::Client<T>,
+            pub type EpochDurationTy = ::core::primitive::u64;
+            pub type ExpectedBlockTimeTy = ::core::primitive::u64;
+            pub type MaxAuthoritiesTy = ::core::primitive::u32;
+            pub struct ConstantsApi<'a, T> {
+                metadata: &'a ::subxt::Metadata,
+                marker: ::core::marker::PhantomData<T>,
             }
             impl<'a, T: ::subxt::Config> ConstantsApi<'a, T> {
                 pub fn new(client: &'a ::subxt::Client<T>) -> Self {
-                    Self { client }
+                    Self::from_metadata(client.metadata())
+                }
+            }
+            impl<'a, T> ConstantsApi<'a, T> {
+                #[doc = r" Read constants straight from `metadata`, without a client to fetch it with."]
+                #[doc = r""]
+                #[doc = r" `T` is only needed by [`Self::new`], so any type will do here."]
+                pub fn from_metadata(metadata: &'a ::subxt::Metadata) -> Self {
+                    Self {
+                        metadata,
+                        marker: ::core::marker::PhantomData,
+                    }
                 }
+                #[doc = " The amount of time, in slots, that each epoch should last."]
+                #[doc = " NOTE: Currently it is not possible to change the epoch duration after"]
+                #[doc = " the chain has started. Attempting to do so will brick block production."]
                 pub fn epoch_duration(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u64, ::subxt::BasicError>
+                ) -> ::core::result::Result<EpochDurationTy, ::subxt::BasicError>
                 {
-                    let pallet = self.client.metadata().pallet("Babe")?;
-                    let constant = pallet.constant("EpochDuration")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("Babe")?;
+                    let value =
+                        pallet.decode_constant::<EpochDurationTy>("EpochDuration")?;
+                    Ok(value)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn epoch_duration_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Babe")?;
+                    let value = pallet.raw_constant("EpochDuration")?;
                     Ok(value)
                 }
+                #[doc = " The expected average block time at which BABE should be creating"]
+                #[doc = " blocks. Since BABE is probabilistic it is not trivial to figure out"]
+                #[doc = " what the expected average block time should be based on the slot"]
+                #[doc = " duration and the security parameter `c` (where `1 - c` represents"]
+                #[doc = " the probability of a slot being empty)."]
                 pub fn expected_block_time(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u64, ::subxt::BasicError>
+                ) -> ::core::result::Result<ExpectedBlockTimeTy, ::subxt::BasicError>
                 {
-                    let pallet = self.client.metadata().pallet("Babe")?;
-                    let constant = pallet.constant("ExpectedBlockTime")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("Babe")?;
+                    let value = pallet
+                        .decode_constant::<ExpectedBlockTimeTy>("ExpectedBlockTime")?;
+                    Ok(value)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn expected_block_time_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Babe")?;
+                    let value = pallet.raw_constant("ExpectedBlockTime")?;
                     Ok(value)
                 }
+                #[doc = " Max number of authorities allowed"]
                 pub fn max_authorities(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u32, ::subxt::BasicError>
+                ) -> ::core::result::Result<MaxAuthoritiesTy, ::subxt::BasicError>
                 {
-                    let pallet = self.client.metadata().pallet("Babe")?;
-                    let constant = pallet.constant("MaxAuthorities")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("Babe")?;
+                    let value =
+                        pallet.decode_constant::<MaxAuthoritiesTy>("MaxAuthorities")?;
+                    Ok(value)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn max_authorities_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Babe")?;
+                    let value = pallet.raw_constant("MaxAuthorities")?;
                     Ok(value)
                 }
+                #[doc = r" Returns the name and raw SCALE encoded value of every constant in this pallet."]
+                pub fn all(
+                    &self,
+                ) -> ::core::result::Result<
+                    ::std::vec::Vec<(
+                        &'static str,
+                        ::std::vec::Vec<::core::primitive::u8>,
+                    )>,
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Babe")?;
+                    let mut constants = ::std::vec::Vec::new();
+                    constants.push((
+                        "EpochDuration",
+                        pallet.constant("EpochDuration")?.value.clone(),
+                    ));
+                    constants.push((
+                        "ExpectedBlockTime",
+                        pallet.constant("ExpectedBlockTime")?.value.clone(),
+                    ));
+                    constants.push((
+                        "MaxAuthorities",
+                        pallet.constant("MaxAuthorities")?.value.clone(),
+                    ));
+                    Ok(constants)
+                }
             }
         }
     }
@@ -2041,22 +2336,71 @@ pub mod api {
         }
         pub mod constants {
             use super::runtime_types;
-            pub struct ConstantsApi<'a, T: ::subxt::Config> {
-                client: &'a ::subxt::Client<T>,
+            pub type MinimumPeriodTy = ::core::primitive::u64;
+            pub struct ConstantsApi<'a, T> {
+                metadata: &'a ::subxt::Metadata,
+                marker: ::core::marker::PhantomData<T>,
             }
             impl<'a, T: ::subxt::Config> ConstantsApi<'a, T> {
                 pub fn new(client: &'a ::subxt::Client<T>) -> Self {
-                    Self { client }
+                    Self::from_metadata(client.metadata())
+                }
+            }
+            impl<'a, T> ConstantsApi<'a, T> {
+                #[doc = r" Read constants straight from `metadata`, without a client to fetch it with."]
+                #[doc = r""]
+                #[doc = r" `T` is only needed by [`Self::new`], so any type will do here."]
+                pub fn from_metadata(metadata: &'a ::subxt::Metadata) -> Self {
+                    Self {
+                        metadata,
+                        marker: ::core::marker::PhantomData,
+                    }
                 }
+                #[doc = " The minimum period between blocks. Beware that this is different to the *expected*"]
+                #[doc = " period that the block production apparatus provides. Your chosen consensus system will"]
+                #[doc = " generally work with this to determine a sensible block time. e.g. For Aura, it will be"]
+                #[doc = " double this period on default settings."]
                 pub fn minimum_period(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u64, ::subxt::BasicError>
+                ) -> ::core::result::Result<MinimumPeriodTy, ::subxt::BasicError>
                 {
-                    let pallet = self.client.metadata().pallet("Timestamp")?;
-                    let constant = pallet.constant("MinimumPeriod")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("Timestamp")?;
+                    let value =
+                        pallet.decode_constant::<MinimumPeriodTy>("MinimumPeriod")?;
+                    Ok(value)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn minimum_period_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Timestamp")?;
+                    let value = pallet.raw_constant("MinimumPeriod")?;
                     Ok(value)
                 }
+                #[doc = r" Returns the name and raw SCALE encoded value of every constant in this pallet."]
+                pub fn all(
+                    &self,
+                ) -> ::core::result::Result<
+                    ::std::vec::Vec<(
+                        &'static str,
+                        ::std::vec::Vec<::core::primitive::u8>,
+                    )>,
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Timestamp")?;
+                    let mut constants = ::std::vec::Vec::new();
+                    constants.push((
+                        "MinimumPeriod",
+                        pallet.constant("MinimumPeriod")?.value.clone(),
+                    ));
+                    Ok(constants)
+                }
             }
         }
     }
@@ -2303,22 +2647,65 @@ pub mod api {
         }
         pub mod constants {
             use super::runtime_types;
-            pub struct ConstantsApi<'a, T: ::subxt::Config> {
-                client: &'a ::subxt::Client<T>,
+            pub type DepositTy = ::core::primitive::u128;
+            pub struct ConstantsApi<'a, T> {
+                metadata: &'a ::subxt::Metadata,
+                marker: ::core::marker::PhantomData<T>,
             }
             impl<'a, T: ::subxt::Config> ConstantsApi<'a, T> {
                 pub fn new(client: &'a ::subxt::Client<T>) -> Self {
-                    Self { client }
+                    Self::from_metadata(client.metadata())
+                }
+            }
+            impl<'a, T> ConstantsApi<'a, T> {
+                #[doc = r" Read constants straight from `metadata`, without a client to fetch it with."]
+                #[doc = r""]
+                #[doc = r" `T` is only needed by [`Self::new`], so any type will do here."]
+                pub fn from_metadata(metadata: &'a ::subxt::Metadata) -> Self {
+                    Self {
+                        metadata,
+                        marker: ::core::marker::PhantomData,
+                    }
                 }
+                #[doc = " The deposit needed for reserving an index."]
                 pub fn deposit(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u128, ::subxt::BasicError>
+                ) -> ::core::result::Result<DepositTy, ::subxt::BasicError>
                 {
-                    let pallet = self.client.metadata().pallet("Indices")?;
-                    let constant = pallet.constant("Deposit")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("Indices")?;
+                    let value = pallet.decode_constant::<DepositTy>("Deposit")?;
+                    Ok(value)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn deposit_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Indices")?;
+                    let value = pallet.raw_constant("Deposit")?;
                     Ok(value)
                 }
+                #[doc = r" Returns the name and raw SCALE encoded value of every constant in this pallet."]
+                pub fn all(
+                    &self,
+                ) -> ::core::result::Result<
+                    ::std::vec::Vec<(
+                        &'static str,
+                        ::std::vec::Vec<::core::primitive::u8>,
+                    )>,
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Indices")?;
+                    let mut constants = ::std::vec::Vec::new();
+                    constants
+                        .push(("Deposit", pallet.constant("Deposit")?.value.clone()));
+                    Ok(constants)
+                }
             }
         }
     }
@@ -2797,40 +3184,123 @@ pub mod api {
         }
         pub mod constants {
             use super::runtime_types;
-            pub struct ConstantsApi<'a, T: ::subxt::Config> {
-                client: &'a ::subxt::Client<T>,
+            pub type ExistentialDepositTy = ::core::primitive::u128;
+            pub type MaxLocksTy = ::core::primitive::u32;
+            pub type MaxReservesTy = ::core::primitive::u32;
+            pub struct ConstantsApi<'a, T> {
+                metadata: &'a ::subxt::Metadata,
+                marker: ::core::marker::PhantomData<T>,
             }
             impl<'a, T: ::subxt::Config> ConstantsApi<'a, T> {
                 pub fn new(client: &'a ::subxt::Client<T>) -> Self {
-                    Self { client }
+                    Self::from_metadata(client.metadata())
+                }
+            }
+            impl<'a, T> ConstantsApi<'a, T> {
+                #[doc = r" Read constants straight from `metadata`, without a client to fetch it with."]
+                #[doc = r""]
+                #[doc = r" `T` is only needed by [`Self::new`], so any type will do here."]
+                pub fn from_metadata(metadata: &'a ::subxt::Metadata) -> Self {
+                    Self {
+                        metadata,
+                        marker: ::core::marker::PhantomData,
+                    }
                 }
+                #[doc = " The minimum amount required to keep an account open."]
                 pub fn existential_deposit(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u128, ::subxt::BasicError>
+                ) -> ::core::result::Result<ExistentialDepositTy, ::subxt::BasicError>
                 {
-                    let pallet = self.client.metadata().pallet("Balances")?;
-                    let constant = pallet.constant("ExistentialDeposit")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("Balances")?;
+                    let value = pallet
+                        .decode_constant::<ExistentialDepositTy>("ExistentialDeposit")?;
+                    Ok(value)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn existential_deposit_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Balances")?;
+                    let value = pallet.raw_constant("ExistentialDeposit")?;
                     Ok(value)
                 }
+                #[doc = " The maximum number of locks that should exist on an account."]
+                #[doc = " Not strictly enforced, but used for weight estimation."]
                 pub fn max_locks(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u32, ::subxt::BasicError>
+                ) -> ::core::result::Result<MaxLocksTy, ::subxt::BasicError>
                 {
-                    let pallet = self.client.metadata().pallet("Balances")?;
-                    let constant = pallet.constant("MaxLocks")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("Balances")?;
+                    let value = pallet.decode_constant::<MaxLocksTy>("MaxLocks")?;
                     Ok(value)
                 }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn max_locks_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Balances")?;
+                    let value = pallet.raw_constant("MaxLocks")?;
+                    Ok(value)
+                }
+                #[doc = " The maximum number of named reserves that can exist on an account."]
                 pub fn max_reserves(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u32, ::subxt::BasicError>
+                ) -> ::core::result::Result<MaxReservesTy, ::subxt::BasicError>
                 {
-                    let pallet = self.client.metadata().pallet("Balances")?;
-                    let constant = pallet.constant("MaxReserves")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("Balances")?;
+                    let value = pallet.decode_constant::<MaxReservesTy>("MaxReserves")?;
+                    Ok(value)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn max_reserves_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Balances")?;
+                    let value = pallet.raw_constant("MaxReserves")?;
                     Ok(value)
                 }
+                #[doc = r" Returns the name and raw SCALE encoded value of every constant in this pallet."]
+                pub fn all(
+                    &self,
+                ) -> ::core::result::Result<
+                    ::std::vec::Vec<(
+                        &'static str,
+                        ::std::vec::Vec<::core::primitive::u8>,
+                    )>,
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Balances")?;
+                    let mut constants = ::std::vec::Vec::new();
+                    constants.push((
+                        "ExistentialDeposit",
+                        pallet.constant("ExistentialDeposit")?.value.clone(),
+                    ));
+                    constants
+                        .push(("MaxLocks", pallet.constant("MaxLocks")?.value.clone()));
+                    constants.push((
+                        "MaxReserves",
+                        pallet.constant("MaxReserves")?.value.clone(),
+                    ));
+                    Ok(constants)
+                }
             }
         }
     }
@@ -2888,46 +3358,150 @@ pub mod api {
         }
         pub mod constants {
             use super::runtime_types;
-            pub struct ConstantsApi<'a, T: ::subxt::Config> {
-                client: &'a ::subxt::Client<T>,
+            pub type TransactionByteFeeTy = ::core::primitive::u128;
+            pub type OperationalFeeMultiplierTy = ::core::primitive::u8;
+            pub type WeightToFeeTy = ::std::vec::Vec<
+                runtime_types::frame_support::weights::WeightToFeeCoefficient<
+                    ::core::primitive::u128,
+                >,
+            >;
+            pub struct ConstantsApi<'a, T> {
+                metadata: &'a ::subxt::Metadata,
+                marker: ::core::marker::PhantomData<T>,
             }
             impl<'a, T: ::subxt::Config> ConstantsApi<'a, T> {
                 pub fn new(client: &'a ::subxt::Client<T>) -> Self {
-                    Self { client }
+                    Self::from_metadata(client.metadata())
+                }
+            }
+            impl<'a, T> ConstantsApi<'a, T> {
+                #[doc = r" Read constants straight from `metadata`, without a client to fetch it with."]
+                #[doc = r""]
+                #[doc = r" `T` is only needed by [`Self::new`], so any type will do here."]
+                pub fn from_metadata(metadata: &'a ::subxt::Metadata) -> Self {
+                    Self {
+                        metadata,
+                        marker: ::core::marker::PhantomData,
+                    }
                 }
+                #[doc = " The fee to be paid for making a transaction; the per-byte portion."]
                 pub fn transaction_byte_fee(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u128, ::subxt::BasicError>
+                ) -> ::core::result::Result<TransactionByteFeeTy, ::subxt::BasicError>
                 {
-                    let pallet = self.client.metadata().pallet("TransactionPayment")?;
-                    let constant = pallet.constant("TransactionByteFee")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("TransactionPayment")?;
+                    let value = pallet
+                        .decode_constant::<TransactionByteFeeTy>("TransactionByteFee")?;
+                    Ok(value)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn transaction_byte_fee_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("TransactionPayment")?;
+                    let value = pallet.raw_constant("TransactionByteFee")?;
                     Ok(value)
                 }
+                #[doc = " A fee mulitplier for `Operational` extrinsics to compute \"virtual tip\" to boost their"]
+                #[doc = " `priority`"]
+                #[doc = ""]
+                #[doc = " This value is multipled by the `final_fee` to obtain a \"virtual tip\" that is later"]
+                #[doc = " added to a tip component in regular `priority` calculations."]
+                #[doc = " It means that a `Normal` transaction can front-run a similarly-sized `Operational`"]
+                #[doc = " extrinsic (with no tip), by including a tip value greater than the virtual tip."]
+                #[doc = ""]
+                #[doc = " ```rust,ignore"]
+                #[doc = " // For `Normal`"]
+                #[doc = " let priority = priority_calc(tip);"]
+                #[doc = ""]
+                #[doc = " // For `Operational`"]
+                #[doc = " let virtual_tip = (inclusion_fee + tip) * OperationalFeeMultiplier;"]
+                #[doc = " let priority = priority_calc(tip + virtual_tip);"]
+                #[doc = " ```"]
+                #[doc = ""]
+                #[doc = " Note that since we use `final_fee` the multiplier applies also to the regular `tip`"]
+                #[doc = " sent with the transaction. So, not only does the transaction get a priority bump based"]
+                #[doc = " on the `inclusion_fee`, but we also amplify the impact of tips applied to `Operational`"]
+                #[doc = " transactions."]
                 pub fn operational_fee_multiplier(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u8, ::subxt::BasicError>
+                ) -> ::core::result::Result<OperationalFeeMultiplierTy, ::subxt::BasicError>
                 {
-                    let pallet = self.client.metadata().pallet("TransactionPayment")?;
-                    let constant = pallet.constant("OperationalFeeMultiplier")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("TransactionPayment")?;
+                    let value = pallet.decode_constant::<OperationalFeeMultiplierTy>(
+                        "OperationalFeeMultiplier",
+                    )?;
+                    Ok(value)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn operational_fee_multiplier_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("TransactionPayment")?;
+                    let value = pallet.raw_constant("OperationalFeeMultiplier")?;
                     Ok(value)
                 }
+                #[doc = " The polynomial that is applied in order to derive fee from weight."]
                 pub fn weight_to_fee(
                     &self,
+                ) -> ::core::result::Result<WeightToFeeTy, ::subxt::BasicError>
+                {
+                    let pallet = self.metadata.pallet("TransactionPayment")?;
+                    let value = pallet.decode_constant::<WeightToFeeTy>("WeightToFee")?;
+                    Ok(value)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn weight_to_fee_raw(
+                    &self,
                 ) -> ::core::result::Result<
-                    ::std::vec::Vec<
-                        runtime_types::frame_support::weights::WeightToFeeCoefficient<
-                            ::core::primitive::u128,
-                        >,
-                    >,
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
                     ::subxt::BasicError,
                 > {
-                    let pallet = self.client.metadata().pallet("TransactionPayment")?;
-                    let constant = pallet.constant("WeightToFee")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("TransactionPayment")?;
+                    let value = pallet.raw_constant("WeightToFee")?;
                     Ok(value)
                 }
+                #[doc = r" Returns the name and raw SCALE encoded value of every constant in this pallet."]
+                pub fn all(
+                    &self,
+                ) -> ::core::result::Result<
+                    ::std::vec::Vec<(
+                        &'static str,
+                        ::std::vec::Vec<::core::primitive::u8>,
+                    )>,
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("TransactionPayment")?;
+                    let mut constants = ::std::vec::Vec::new();
+                    constants.push((
+                        "TransactionByteFee",
+                        pallet.constant("TransactionByteFee")?.value.clone(),
+                    ));
+                    constants.push((
+                        "OperationalFeeMultiplier",
+                        pallet.constant("OperationalFeeMultiplier")?.value.clone(),
+                    ));
+                    constants.push((
+                        "WeightToFee",
+                        pallet.constant("WeightToFee")?.value.clone(),
+                    ));
+                    Ok(constants)
+                }
             }
         }
     }
@@ -3067,22 +3641,70 @@ pub mod api {
         }
         pub mod constants {
             use super::runtime_types;
-            pub struct ConstantsApi<'a, T: ::subxt::Config> {
-                client: &'a ::subxt::Client<T>,
+            pub type UncleGenerationsTy = ::core::primitive::u32;
+            pub struct ConstantsApi<'a, T> {
+                metadata: &'a ::subxt::Metadata,
+                marker: ::core::marker::PhantomData<T>,
             }
             impl<'a, T: ::subxt::Config> ConstantsApi<'a, T> {
                 pub fn new(client: &'a ::subxt::Client<T>) -> Self {
-                    Self { client }
+                    Self::from_metadata(client.metadata())
+                }
+            }
+            impl<'a, T> ConstantsApi<'a, T> {
+                #[doc = r" Read constants straight from `metadata`, without a client to fetch it with."]
+                #[doc = r""]
+                #[doc = r" `T` is only needed by [`Self::new`], so any type will do here."]
+                pub fn from_metadata(metadata: &'a ::subxt::Metadata) -> Self {
+                    Self {
+                        metadata,
+                        marker: ::core::marker::PhantomData,
+                    }
                 }
+                #[doc = " The number of blocks back we should accept uncles."]
+                #[doc = " This means that we will deal with uncle-parents that are"]
+                #[doc = " `UncleGenerations + 1` before `now`."]
                 pub fn uncle_generations(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u32, ::subxt::BasicError>
+                ) -> ::core::result::Result<UncleGenerationsTy, ::subxt::BasicError>
                 {
-                    let pallet = self.client.metadata().pallet("Authorship")?;
-                    let constant = pallet.constant("UncleGenerations")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("Authorship")?;
+                    let value = pallet
+                        .decode_constant::<UncleGenerationsTy>("UncleGenerations")?;
+                    Ok(value)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn uncle_generations_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Authorship")?;
+                    let value = pallet.raw_constant("UncleGenerations")?;
                     Ok(value)
                 }
+                #[doc = r" Returns the name and raw SCALE encoded value of every constant in this pallet."]
+                pub fn all(
+                    &self,
+                ) -> ::core::result::Result<
+                    ::std::vec::Vec<(
+                        &'static str,
+                        ::std::vec::Vec<::core::primitive::u8>,
+                    )>,
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Authorship")?;
+                    let mut constants = ::std::vec::Vec::new();
+                    constants.push((
+                        "UncleGenerations",
+                        pallet.constant("UncleGenerations")?.value.clone(),
+                    ));
+                    Ok(constants)
+                }
             }
         }
     }
@@ -4957,67 +5579,228 @@ pub mod api {
         }
         pub mod constants {
             use super::runtime_types;
-            pub struct ConstantsApi<'a, T: ::subxt::Config> {
-                client: &'a ::subxt::Client<T>,
+            pub type MaxNominationsTy = ::core::primitive::u32;
+            pub type SessionsPerEraTy = ::core::primitive::u32;
+            pub type BondingDurationTy = ::core::primitive::u32;
+            pub type SlashDeferDurationTy = ::core::primitive::u32;
+            pub type MaxNominatorRewardedPerValidatorTy = ::core::primitive::u32;
+            pub type MaxUnlockingChunksTy = ::core::primitive::u32;
+            pub struct ConstantsApi<'a, T> {
+                metadata: &'a ::subxt::Metadata,
+                marker: ::core::marker::PhantomData<T>,
             }
             impl<'a, T: ::subxt::Config> ConstantsApi<'a, T> {
                 pub fn new(client: &'a ::subxt::Client<T>) -> Self {
-                    Self { client }
+                    Self::from_metadata(client.metadata())
+                }
+            }
+            impl<'a, T> ConstantsApi<'a, T> {
+                #[doc = r" Read constants straight from `metadata`, without a client to fetch it with."]
+                #[doc = r""]
+                #[doc = r" `T` is only needed by [`Self::new`], so any type will do here."]
+                pub fn from_metadata(metadata: &'a ::subxt::Metadata) -> Self {
+                    Self {
+                        metadata,
+                        marker: ::core::marker::PhantomData,
+                    }
                 }
+                #[doc = " Maximum number of nominations per nominator."]
                 pub fn max_nominations(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u32, ::subxt::BasicError>
+                ) -> ::core::result::Result<MaxNominationsTy, ::subxt::BasicError>
                 {
-                    let pallet = self.client.metadata().pallet("Staking")?;
-                    let constant = pallet.constant("MaxNominations")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("Staking")?;
+                    let value =
+                        pallet.decode_constant::<MaxNominationsTy>("MaxNominations")?;
+                    Ok(value)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn max_nominations_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Staking")?;
+                    let value = pallet.raw_constant("MaxNominations")?;
                     Ok(value)
                 }
+                #[doc = " Number of sessions per era."]
                 pub fn sessions_per_era(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u32, ::subxt::BasicError>
+                ) -> ::core::result::Result<SessionsPerEraTy, ::subxt::BasicError>
                 {
-                    let pallet = self.client.metadata().pallet("Staking")?;
-                    let constant = pallet.constant("SessionsPerEra")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("Staking")?;
+                    let value =
+                        pallet.decode_constant::<SessionsPerEraTy>("SessionsPerEra")?;
+                    Ok(value)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn sessions_per_era_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Staking")?;
+                    let value = pallet.raw_constant("SessionsPerEra")?;
                     Ok(value)
                 }
+                #[doc = " Number of eras that staked funds must remain bonded for."]
                 pub fn bonding_duration(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u32, ::subxt::BasicError>
+                ) -> ::core::result::Result<BondingDurationTy, ::subxt::BasicError>
                 {
-                    let pallet = self.client.metadata().pallet("Staking")?;
-                    let constant = pallet.constant("BondingDuration")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("Staking")?;
+                    let value =
+                        pallet.decode_constant::<BondingDurationTy>("BondingDuration")?;
+                    Ok(value)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn bonding_duration_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Staking")?;
+                    let value = pallet.raw_constant("BondingDuration")?;
                     Ok(value)
                 }
+                #[doc = " Number of eras that slashes are deferred by, after computation."]
+                #[doc = ""]
+                #[doc = " This should be less than the bonding duration. Set to 0 if slashes"]
+                #[doc = " should be applied immediately, without opportunity for intervention."]
                 pub fn slash_defer_duration(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u32, ::subxt::BasicError>
+                ) -> ::core::result::Result<SlashDeferDurationTy, ::subxt::BasicError>
                 {
-                    let pallet = self.client.metadata().pallet("Staking")?;
-                    let constant = pallet.constant("SlashDeferDuration")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("Staking")?;
+                    let value = pallet
+                        .decode_constant::<SlashDeferDurationTy>("SlashDeferDuration")?;
+                    Ok(value)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn slash_defer_duration_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Staking")?;
+                    let value = pallet.raw_constant("SlashDeferDuration")?;
                     Ok(value)
                 }
+                #[doc = " The maximum number of nominators rewarded for each validator."]
+                #[doc = ""]
+                #[doc = " For each validator only the `$MaxNominatorRewardedPerValidator` biggest stakers can"]
+                #[doc = " claim their reward. This used to limit the i/o cost for the nominator payout."]
                 pub fn max_nominator_rewarded_per_validator(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u32, ::subxt::BasicError>
-                {
-                    let pallet = self.client.metadata().pallet("Staking")?;
-                    let constant = pallet.constant("MaxNominatorRewardedPerValidator")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                ) -> ::core::result::Result<
+                    MaxNominatorRewardedPerValidatorTy,
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Staking")?;
+                    let value = pallet
+                        .decode_constant::<MaxNominatorRewardedPerValidatorTy>(
+                            "MaxNominatorRewardedPerValidator",
+                        )?;
+                    Ok(value)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn max_nominator_rewarded_per_validator_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Staking")?;
+                    let value =
+                        pallet.raw_constant("MaxNominatorRewardedPerValidator")?;
                     Ok(value)
                 }
+                #[doc = " The maximum number of `unlocking` chunks a [`StakingLedger`] can have. Effectively"]
+                #[doc = " determines how many unique eras a staker may be unbonding in."]
                 pub fn max_unlocking_chunks(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u32, ::subxt::BasicError>
+                ) -> ::core::result::Result<MaxUnlockingChunksTy, ::subxt::BasicError>
                 {
-                    let pallet = self.client.metadata().pallet("Staking")?;
-                    let constant = pallet.constant("MaxUnlockingChunks")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("Staking")?;
+                    let value = pallet
+                        .decode_constant::<MaxUnlockingChunksTy>("MaxUnlockingChunks")?;
+                    Ok(value)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn max_unlocking_chunks_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Staking")?;
+                    let value = pallet.raw_constant("MaxUnlockingChunks")?;
                     Ok(value)
                 }
+                #[doc = r" Returns the name and raw SCALE encoded value of every constant in this pallet."]
+                pub fn all(
+                    &self,
+                ) -> ::core::result::Result<
+                    ::std::vec::Vec<(
+                        &'static str,
+                        ::std::vec::Vec<::core::primitive::u8>,
+                    )>,
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Staking")?;
+                    let mut constants = ::std::vec::Vec::new();
+                    constants.push((
+                        "MaxNominations",
+                        pallet.constant("MaxNominations")?.value.clone(),
+                    ));
+                    constants.push((
+                        "SessionsPerEra",
+                        pallet.constant("SessionsPerEra")?.value.clone(),
+                    ));
+                    constants.push((
+                        "BondingDuration",
+                        pallet.constant("BondingDuration")?.value.clone(),
+                    ));
+                    constants.push((
+                        "SlashDeferDuration",
+                        pallet.constant("SlashDeferDuration")?.value.clone(),
+                    ));
+                    constants.push((
+                        "MaxNominatorRewardedPerValidator",
+                        pallet
+                            .constant("MaxNominatorRewardedPerValidator")?
+                            .value
+                            .clone(),
+                    ));
+                    constants.push((
+                        "MaxUnlockingChunks",
+                        pallet.constant("MaxUnlockingChunks")?.value.clone(),
+                    ));
+                    Ok(constants)
+                }
             }
         }
     }
@@ -5730,22 +6513,68 @@ pub mod api {
         }
         pub mod constants {
             use super::runtime_types;
-            pub struct ConstantsApi<'a, T: ::subxt::Config> {
-                client: &'a ::subxt::Client<T>,
+            pub type MaxAuthoritiesTy = ::core::primitive::u32;
+            pub struct ConstantsApi<'a, T> {
+                metadata: &'a ::subxt::Metadata,
+                marker: ::core::marker::PhantomData<T>,
             }
             impl<'a, T: ::subxt::Config> ConstantsApi<'a, T> {
                 pub fn new(client: &'a ::subxt::Client<T>) -> Self {
-                    Self { client }
+                    Self::from_metadata(client.metadata())
+                }
+            }
+            impl<'a, T> ConstantsApi<'a, T> {
+                #[doc = r" Read constants straight from `metadata`, without a client to fetch it with."]
+                #[doc = r""]
+                #[doc = r" `T` is only needed by [`Self::new`], so any type will do here."]
+                pub fn from_metadata(metadata: &'a ::subxt::Metadata) -> Self {
+                    Self {
+                        metadata,
+                        marker: ::core::marker::PhantomData,
+                    }
                 }
+                #[doc = " Max Authorities in use"]
                 pub fn max_authorities(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u32, ::subxt::BasicError>
+                ) -> ::core::result::Result<MaxAuthoritiesTy, ::subxt::BasicError>
                 {
-                    let pallet = self.client.metadata().pallet("Grandpa")?;
-                    let constant = pallet.constant("MaxAuthorities")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("Grandpa")?;
+                    let value =
+                        pallet.decode_constant::<MaxAuthoritiesTy>("MaxAuthorities")?;
+                    Ok(value)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn max_authorities_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Grandpa")?;
+                    let value = pallet.raw_constant("MaxAuthorities")?;
                     Ok(value)
                 }
+                #[doc = r" Returns the name and raw SCALE encoded value of every constant in this pallet."]
+                pub fn all(
+                    &self,
+                ) -> ::core::result::Result<
+                    ::std::vec::Vec<(
+                        &'static str,
+                        ::std::vec::Vec<::core::primitive::u8>,
+                    )>,
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Grandpa")?;
+                    let mut constants = ::std::vec::Vec::new();
+                    constants.push((
+                        "MaxAuthorities",
+                        pallet.constant("MaxAuthorities")?.value.clone(),
+                    ));
+                    Ok(constants)
+                }
             }
         }
     }
@@ -5967,22 +6796,71 @@ pub mod api {
         }
         pub mod constants {
             use super::runtime_types;
-            pub struct ConstantsApi<'a, T: ::subxt::Config> {
-                client: &'a ::subxt::Client<T>,
+            pub type UnsignedPriorityTy = ::core::primitive::u64;
+            pub struct ConstantsApi<'a, T> {
+                metadata: &'a ::subxt::Metadata,
+                marker: ::core::marker::PhantomData<T>,
             }
             impl<'a, T: ::subxt::Config> ConstantsApi<'a, T> {
                 pub fn new(client: &'a ::subxt::Client<T>) -> Self {
-                    Self { client }
+                    Self::from_metadata(client.metadata())
+                }
+            }
+            impl<'a, T> ConstantsApi<'a, T> {
+                #[doc = r" Read constants straight from `metadata`, without a client to fetch it with."]
+                #[doc = r""]
+                #[doc = r" `T` is only needed by [`Self::new`], so any type will do here."]
+                pub fn from_metadata(metadata: &'a ::subxt::Metadata) -> Self {
+                    Self {
+                        metadata,
+                        marker: ::core::marker::PhantomData,
+                    }
                 }
+                #[doc = " A configuration for base priority of unsigned transactions."]
+                #[doc = ""]
+                #[doc = " This is exposed so that it can be tuned for particular runtime, when"]
+                #[doc = " multiple pallets send unsigned transactions."]
                 pub fn unsigned_priority(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u64, ::subxt::BasicError>
+                ) -> ::core::result::Result<UnsignedPriorityTy, ::subxt::BasicError>
                 {
-                    let pallet = self.client.metadata().pallet("ImOnline")?;
-                    let constant = pallet.constant("UnsignedPriority")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("ImOnline")?;
+                    let value = pallet
+                        .decode_constant::<UnsignedPriorityTy>("UnsignedPriority")?;
+                    Ok(value)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn unsigned_priority_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("ImOnline")?;
+                    let value = pallet.raw_constant("UnsignedPriority")?;
                     Ok(value)
                 }
+                #[doc = r" Returns the name and raw SCALE encoded value of every constant in this pallet."]
+                pub fn all(
+                    &self,
+                ) -> ::core::result::Result<
+                    ::std::vec::Vec<(
+                        &'static str,
+                        ::std::vec::Vec<::core::primitive::u8>,
+                    )>,
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("ImOnline")?;
+                    let mut constants = ::std::vec::Vec::new();
+                    constants.push((
+                        "UnsignedPriority",
+                        pallet.constant("UnsignedPriority")?.value.clone(),
+                    ));
+                    Ok(constants)
+                }
             }
         }
     }
@@ -7204,112 +8082,369 @@ pub mod api {
         }
         pub mod constants {
             use super::runtime_types;
-            pub struct ConstantsApi<'a, T: ::subxt::Config> {
-                client: &'a ::subxt::Client<T>,
+            pub type EnactmentPeriodTy = ::core::primitive::u32;
+            pub type LaunchPeriodTy = ::core::primitive::u32;
+            pub type VotingPeriodTy = ::core::primitive::u32;
+            pub type VoteLockingPeriodTy = ::core::primitive::u32;
+            pub type MinimumDepositTy = ::core::primitive::u128;
+            pub type InstantAllowedTy = ::core::primitive::bool;
+            pub type FastTrackVotingPeriodTy = ::core::primitive::u32;
+            pub type CooloffPeriodTy = ::core::primitive::u32;
+            pub type PreimageByteDepositTy = ::core::primitive::u128;
+            pub type MaxVotesTy = ::core::primitive::u32;
+            pub type MaxProposalsTy = ::core::primitive::u32;
+            pub struct ConstantsApi<'a, T> {
+                metadata: &'a ::subxt::Metadata,
+                marker: ::core::marker::PhantomData<T>,
             }
             impl<'a, T: ::subxt::Config> ConstantsApi<'a, T> {
                 pub fn new(client: &'a ::subxt::Client<T>) -> Self {
-                    Self { client }
+                    Self::from_metadata(client.metadata())
+                }
+            }
+            impl<'a, T> ConstantsApi<'a, T> {
+                #[doc = r" Read constants straight from `metadata`, without a client to fetch it with."]
+                #[doc = r""]
+                #[doc = r" `T` is only needed by [`Self::new`], so any type will do here."]
+                pub fn from_metadata(metadata: &'a ::subxt::Metadata) -> Self {
+                    Self {
+                        metadata,
+                        marker: ::core::marker::PhantomData,
+                    }
                 }
+                #[doc = " The period between a proposal being approved and enacted."]
+                #[doc = ""]
+                #[doc = " It should generally be a little more than the unstake period to ensure that"]
+                #[doc = " voting stakers have an opportunity to remove themselves from the system in the case"]
+                #[doc = " where they are on the losing side of a vote."]
                 pub fn enactment_period(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u32, ::subxt::BasicError>
+                ) -> ::core::result::Result<EnactmentPeriodTy, ::subxt::BasicError>
                 {
-                    let pallet = self.client.metadata().pallet("Democracy")?;
-                    let constant = pallet.constant("EnactmentPeriod")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("Democracy")?;
+                    let value =
+                        pallet.decode_constant::<EnactmentPeriodTy>("EnactmentPeriod")?;
+                    Ok(value)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn enactment_period_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Democracy")?;
+                    let value = pallet.raw_constant("EnactmentPeriod")?;
                     Ok(value)
                 }
+                #[doc = " How often (in blocks) new public referenda are launched."]
                 pub fn launch_period(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u32, ::subxt::BasicError>
+                ) -> ::core::result::Result<LaunchPeriodTy, ::subxt::BasicError>
                 {
-                    let pallet = self.client.metadata().pallet("Democracy")?;
-                    let constant = pallet.constant("LaunchPeriod")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("Democracy")?;
+                    let value =
+                        pallet.decode_constant::<LaunchPeriodTy>("LaunchPeriod")?;
                     Ok(value)
                 }
-                pub fn voting_period(
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn launch_period_raw(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u32, ::subxt::BasicError>
-                {
-                    let pallet = self.client.metadata().pallet("Democracy")?;
-                    let constant = pallet.constant("VotingPeriod")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Democracy")?;
+                    let value = pallet.raw_constant("LaunchPeriod")?;
                     Ok(value)
                 }
-                pub fn vote_locking_period(
+                #[doc = " How often (in blocks) to check for new votes."]
+                pub fn voting_period(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u32, ::subxt::BasicError>
+                ) -> ::core::result::Result<VotingPeriodTy, ::subxt::BasicError>
                 {
-                    let pallet = self.client.metadata().pallet("Democracy")?;
-                    let constant = pallet.constant("VoteLockingPeriod")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("Democracy")?;
+                    let value =
+                        pallet.decode_constant::<VotingPeriodTy>("VotingPeriod")?;
                     Ok(value)
                 }
-                pub fn minimum_deposit(
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn voting_period_raw(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u128, ::subxt::BasicError>
-                {
-                    let pallet = self.client.metadata().pallet("Democracy")?;
-                    let constant = pallet.constant("MinimumDeposit")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Democracy")?;
+                    let value = pallet.raw_constant("VotingPeriod")?;
                     Ok(value)
                 }
-                pub fn instant_allowed(
+                #[doc = " The minimum period of vote locking."]
+                #[doc = ""]
+                #[doc = " It should be no shorter than enactment period to ensure that in the case of an approval,"]
+                #[doc = " those successful voters are locked into the consequences that their votes entail."]
+                pub fn vote_locking_period(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::bool, ::subxt::BasicError>
+                ) -> ::core::result::Result<VoteLockingPeriodTy, ::subxt::BasicError>
                 {
-                    let pallet = self.client.metadata().pallet("Democracy")?;
-                    let constant = pallet.constant("InstantAllowed")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("Democracy")?;
+                    let value = pallet
+                        .decode_constant::<VoteLockingPeriodTy>("VoteLockingPeriod")?;
                     Ok(value)
                 }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn vote_locking_period_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Democracy")?;
+                    let value = pallet.raw_constant("VoteLockingPeriod")?;
+                    Ok(value)
+                }
+                #[doc = " The minimum amount to be used as a deposit for a public referendum proposal."]
+                pub fn minimum_deposit(
+                    &self,
+                ) -> ::core::result::Result<MinimumDepositTy, ::subxt::BasicError>
+                {
+                    let pallet = self.metadata.pallet("Democracy")?;
+                    let value =
+                        pallet.decode_constant::<MinimumDepositTy>("MinimumDeposit")?;
+                    Ok(value)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn minimum_deposit_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Democracy")?;
+                    let value = pallet.raw_constant("MinimumDeposit")?;
+                    Ok(value)
+                }
+                #[doc = " Indicator for whether an emergency origin is even allowed to happen. Some chains may"]
+                #[doc = " want to set this permanently to `false`, others may want to condition it on things such"]
+                #[doc = " as an upgrade having happened recently."]
+                pub fn instant_allowed(
+                    &self,
+                ) -> ::core::result::Result<InstantAllowedTy, ::subxt::BasicError>
+                {
+                    let pallet = self.metadata.pallet("Democracy")?;
+                    let value =
+                        pallet.decode_constant::<InstantAllowedTy>("InstantAllowed")?;
+                    Ok(value)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn instant_allowed_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Democracy")?;
+                    let value = pallet.raw_constant("InstantAllowed")?;
+                    Ok(value)
+                }
+                #[doc = " Minimum voting period allowed for a fast-track referendum."]
                 pub fn fast_track_voting_period(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u32, ::subxt::BasicError>
+                ) -> ::core::result::Result<FastTrackVotingPeriodTy, ::subxt::BasicError>
                 {
-                    let pallet = self.client.metadata().pallet("Democracy")?;
-                    let constant = pallet.constant("FastTrackVotingPeriod")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("Democracy")?;
+                    let value = pallet.decode_constant::<FastTrackVotingPeriodTy>(
+                        "FastTrackVotingPeriod",
+                    )?;
+                    Ok(value)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn fast_track_voting_period_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Democracy")?;
+                    let value = pallet.raw_constant("FastTrackVotingPeriod")?;
                     Ok(value)
                 }
+                #[doc = " Period in blocks where an external proposal may not be re-submitted after being vetoed."]
                 pub fn cooloff_period(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u32, ::subxt::BasicError>
+                ) -> ::core::result::Result<CooloffPeriodTy, ::subxt::BasicError>
                 {
-                    let pallet = self.client.metadata().pallet("Democracy")?;
-                    let constant = pallet.constant("CooloffPeriod")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("Democracy")?;
+                    let value =
+                        pallet.decode_constant::<CooloffPeriodTy>("CooloffPeriod")?;
+                    Ok(value)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn cooloff_period_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Democracy")?;
+                    let value = pallet.raw_constant("CooloffPeriod")?;
                     Ok(value)
                 }
+                #[doc = " The amount of balance that must be deposited per byte of preimage stored."]
                 pub fn preimage_byte_deposit(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u128, ::subxt::BasicError>
+                ) -> ::core::result::Result<PreimageByteDepositTy, ::subxt::BasicError>
                 {
-                    let pallet = self.client.metadata().pallet("Democracy")?;
-                    let constant = pallet.constant("PreimageByteDeposit")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("Democracy")?;
+                    let value = pallet.decode_constant::<PreimageByteDepositTy>(
+                        "PreimageByteDeposit",
+                    )?;
+                    Ok(value)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn preimage_byte_deposit_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Democracy")?;
+                    let value = pallet.raw_constant("PreimageByteDeposit")?;
                     Ok(value)
                 }
+                #[doc = " The maximum number of votes for an account."]
+                #[doc = ""]
+                #[doc = " Also used to compute weight, an overly big value can"]
+                #[doc = " lead to extrinsic with very big weight: see `delegate` for instance."]
                 pub fn max_votes(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u32, ::subxt::BasicError>
+                ) -> ::core::result::Result<MaxVotesTy, ::subxt::BasicError>
                 {
-                    let pallet = self.client.metadata().pallet("Democracy")?;
-                    let constant = pallet.constant("MaxVotes")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("Democracy")?;
+                    let value = pallet.decode_constant::<MaxVotesTy>("MaxVotes")?;
+                    Ok(value)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn max_votes_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Democracy")?;
+                    let value = pallet.raw_constant("MaxVotes")?;
                     Ok(value)
                 }
+                #[doc = " The maximum number of public proposals that can exist at any time."]
                 pub fn max_proposals(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u32, ::subxt::BasicError>
+                ) -> ::core::result::Result<MaxProposalsTy, ::subxt::BasicError>
                 {
-                    let pallet = self.client.metadata().pallet("Democracy")?;
-                    let constant = pallet.constant("MaxProposals")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("Democracy")?;
+                    let value =
+                        pallet.decode_constant::<MaxProposalsTy>("MaxProposals")?;
+                    Ok(value)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn max_proposals_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Democracy")?;
+                    let value = pallet.raw_constant("MaxProposals")?;
                     Ok(value)
                 }
+                #[doc = r" Returns the name and raw SCALE encoded value of every constant in this pallet."]
+                pub fn all(
+                    &self,
+                ) -> ::core::result::Result<
+                    ::std::vec::Vec<(
+                        &'static str,
+                        ::std::vec::Vec<::core::primitive::u8>,
+                    )>,
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Democracy")?;
+                    let mut constants = ::std::vec::Vec::new();
+                    constants.push((
+                        "EnactmentPeriod",
+                        pallet.constant("EnactmentPeriod")?.value.clone(),
+                    ));
+                    constants.push((
+                        "LaunchPeriod",
+                        pallet.constant("LaunchPeriod")?.value.clone(),
+                    ));
+                    constants.push((
+                        "VotingPeriod",
+                        pallet.constant("VotingPeriod")?.value.clone(),
+                    ));
+                    constants.push((
+                        "VoteLockingPeriod",
+                        pallet.constant("VoteLockingPeriod")?.value.clone(),
+                    ));
+                    constants.push((
+                        "MinimumDeposit",
+                        pallet.constant("MinimumDeposit")?.value.clone(),
+                    ));
+                    constants.push((
+                        "InstantAllowed",
+                        pallet.constant("InstantAllowed")?.value.clone(),
+                    ));
+                    constants.push((
+                        "FastTrackVotingPeriod",
+                        pallet.constant("FastTrackVotingPeriod")?.value.clone(),
+                    ));
+                    constants.push((
+                        "CooloffPeriod",
+                        pallet.constant("CooloffPeriod")?.value.clone(),
+                    ));
+                    constants.push((
+                        "PreimageByteDeposit",
+                        pallet.constant("PreimageByteDeposit")?.value.clone(),
+                    ));
+                    constants
+                        .push(("MaxVotes", pallet.constant("MaxVotes")?.value.clone()));
+                    constants.push((
+                        "MaxProposals",
+                        pallet.constant("MaxProposals")?.value.clone(),
+                    ));
+                    Ok(constants)
+                }
             }
         }
     }
@@ -8576,78 +9711,244 @@ pub mod api {
         }
         pub mod constants {
             use super::runtime_types;
-            pub struct ConstantsApi<'a, T: ::subxt::Config> {
-                client: &'a ::subxt::Client<T>,
+            pub type PalletIdTy = [::core::primitive::u8; 8usize];
+            pub type CandidacyBondTy = ::core::primitive::u128;
+            pub type VotingBondBaseTy = ::core::primitive::u128;
+            pub type VotingBondFactorTy = ::core::primitive::u128;
+            pub type DesiredMembersTy = ::core::primitive::u32;
+            pub type DesiredRunnersUpTy = ::core::primitive::u32;
+            pub type TermDurationTy = ::core::primitive::u32;
+            pub struct ConstantsApi<'a, T> {
+                metadata: &'a ::subxt::Metadata,
+                marker: ::core::marker::PhantomData<T>,
             }
             impl<'a, T: ::subxt::Config> ConstantsApi<'a, T> {
                 pub fn new(client: &'a ::subxt::Client<T>) -> Self {
-                    Self { client }
+                    Self::from_metadata(client.metadata())
+                }
+            }
+            impl<'a, T> ConstantsApi<'a, T> {
+                #[doc = r" Read constants straight from `metadata`, without a client to fetch it with."]
+                #[doc = r""]
+                #[doc = r" `T` is only needed by [`Self::new`], so any type will do here."]
+                pub fn from_metadata(metadata: &'a ::subxt::Metadata) -> Self {
+                    Self {
+                        metadata,
+                        marker: ::core::marker::PhantomData,
+                    }
                 }
+                #[doc = " Identifier for the elections-phragmen pallet's lock"]
                 pub fn pallet_id(
                     &self,
+                ) -> ::core::result::Result<PalletIdTy, ::subxt::BasicError>
+                {
+                    let pallet = self.metadata.pallet("PhragmenElection")?;
+                    let value = pallet.decode_constant::<PalletIdTy>("PalletId")?;
+                    Ok(value)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn pallet_id_raw(
+                    &self,
                 ) -> ::core::result::Result<
-                    [::core::primitive::u8; 8usize],
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
                     ::subxt::BasicError,
                 > {
-                    let pallet = self.client.metadata().pallet("PhragmenElection")?;
-                    let constant = pallet.constant("PalletId")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("PhragmenElection")?;
+                    let value = pallet.raw_constant("PalletId")?;
                     Ok(value)
                 }
+                #[doc = " How much should be locked up in order to submit one's candidacy."]
                 pub fn candidacy_bond(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u128, ::subxt::BasicError>
+                ) -> ::core::result::Result<CandidacyBondTy, ::subxt::BasicError>
                 {
-                    let pallet = self.client.metadata().pallet("PhragmenElection")?;
-                    let constant = pallet.constant("CandidacyBond")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("PhragmenElection")?;
+                    let value =
+                        pallet.decode_constant::<CandidacyBondTy>("CandidacyBond")?;
+                    Ok(value)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn candidacy_bond_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("PhragmenElection")?;
+                    let value = pallet.raw_constant("CandidacyBond")?;
                     Ok(value)
                 }
+                #[doc = " Base deposit associated with voting."]
+                #[doc = ""]
+                #[doc = " This should be sensibly high to economically ensure the pallet cannot be attacked by"]
+                #[doc = " creating a gigantic number of votes."]
                 pub fn voting_bond_base(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u128, ::subxt::BasicError>
+                ) -> ::core::result::Result<VotingBondBaseTy, ::subxt::BasicError>
                 {
-                    let pallet = self.client.metadata().pallet("PhragmenElection")?;
-                    let constant = pallet.constant("VotingBondBase")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("PhragmenElection")?;
+                    let value =
+                        pallet.decode_constant::<VotingBondBaseTy>("VotingBondBase")?;
+                    Ok(value)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn voting_bond_base_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("PhragmenElection")?;
+                    let value = pallet.raw_constant("VotingBondBase")?;
                     Ok(value)
                 }
+                #[doc = " The amount of bond that need to be locked for each vote (32 bytes)."]
                 pub fn voting_bond_factor(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u128, ::subxt::BasicError>
+                ) -> ::core::result::Result<VotingBondFactorTy, ::subxt::BasicError>
                 {
-                    let pallet = self.client.metadata().pallet("PhragmenElection")?;
-                    let constant = pallet.constant("VotingBondFactor")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("PhragmenElection")?;
+                    let value = pallet
+                        .decode_constant::<VotingBondFactorTy>("VotingBondFactor")?;
+                    Ok(value)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn voting_bond_factor_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("PhragmenElection")?;
+                    let value = pallet.raw_constant("VotingBondFactor")?;
                     Ok(value)
                 }
+                #[doc = " Number of members to elect."]
                 pub fn desired_members(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u32, ::subxt::BasicError>
+                ) -> ::core::result::Result<DesiredMembersTy, ::subxt::BasicError>
                 {
-                    let pallet = self.client.metadata().pallet("PhragmenElection")?;
-                    let constant = pallet.constant("DesiredMembers")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("PhragmenElection")?;
+                    let value =
+                        pallet.decode_constant::<DesiredMembersTy>("DesiredMembers")?;
+                    Ok(value)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn desired_members_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("PhragmenElection")?;
+                    let value = pallet.raw_constant("DesiredMembers")?;
                     Ok(value)
                 }
+                #[doc = " Number of runners_up to keep."]
                 pub fn desired_runners_up(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u32, ::subxt::BasicError>
+                ) -> ::core::result::Result<DesiredRunnersUpTy, ::subxt::BasicError>
                 {
-                    let pallet = self.client.metadata().pallet("PhragmenElection")?;
-                    let constant = pallet.constant("DesiredRunnersUp")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("PhragmenElection")?;
+                    let value = pallet
+                        .decode_constant::<DesiredRunnersUpTy>("DesiredRunnersUp")?;
+                    Ok(value)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn desired_runners_up_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("PhragmenElection")?;
+                    let value = pallet.raw_constant("DesiredRunnersUp")?;
                     Ok(value)
                 }
+                #[doc = " How long each seat is kept. This defines the next block number at which an election"]
+                #[doc = " round will happen. If set to zero, no elections are ever triggered and the module will"]
+                #[doc = " be in passive mode."]
                 pub fn term_duration(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u32, ::subxt::BasicError>
+                ) -> ::core::result::Result<TermDurationTy, ::subxt::BasicError>
                 {
-                    let pallet = self.client.metadata().pallet("PhragmenElection")?;
-                    let constant = pallet.constant("TermDuration")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("PhragmenElection")?;
+                    let value =
+                        pallet.decode_constant::<TermDurationTy>("TermDuration")?;
+                    Ok(value)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn term_duration_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("PhragmenElection")?;
+                    let value = pallet.raw_constant("TermDuration")?;
                     Ok(value)
                 }
+                #[doc = r" Returns the name and raw SCALE encoded value of every constant in this pallet."]
+                pub fn all(
+                    &self,
+                ) -> ::core::result::Result<
+                    ::std::vec::Vec<(
+                        &'static str,
+                        ::std::vec::Vec<::core::primitive::u8>,
+                    )>,
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("PhragmenElection")?;
+                    let mut constants = ::std::vec::Vec::new();
+                    constants
+                        .push(("PalletId", pallet.constant("PalletId")?.value.clone()));
+                    constants.push((
+                        "CandidacyBond",
+                        pallet.constant("CandidacyBond")?.value.clone(),
+                    ));
+                    constants.push((
+                        "VotingBondBase",
+                        pallet.constant("VotingBondBase")?.value.clone(),
+                    ));
+                    constants.push((
+                        "VotingBondFactor",
+                        pallet.constant("VotingBondFactor")?.value.clone(),
+                    ));
+                    constants.push((
+                        "DesiredMembers",
+                        pallet.constant("DesiredMembers")?.value.clone(),
+                    ));
+                    constants.push((
+                        "DesiredRunnersUp",
+                        pallet.constant("DesiredRunnersUp")?.value.clone(),
+                    ));
+                    constants.push((
+                        "TermDuration",
+                        pallet.constant("TermDuration")?.value.clone(),
+                    ));
+                    Ok(constants)
+                }
             }
         }
     }
@@ -9201,86 +10502,258 @@ pub mod api {
         }
         pub mod constants {
             use super::runtime_types;
-            pub struct ConstantsApi<'a, T: ::subxt::Config> {
-                client: &'a ::subxt::Client<T>,
+            pub type ProposalBondTy = runtime_types::sp_arithmetic::per_things::Permill;
+            pub type ProposalBondMinimumTy = ::core::primitive::u128;
+            pub type ProposalBondMaximumTy =
+                ::core::option::Option<::core::primitive::u128>;
+            pub type SpendPeriodTy = ::core::primitive::u32;
+            pub type BurnTy = runtime_types::sp_arithmetic::per_things::Permill;
+            pub type PalletIdTy = runtime_types::frame_support::PalletId;
+            pub type MaxApprovalsTy = ::core::primitive::u32;
+            pub struct ConstantsApi<'a, T> {
+                metadata: &'a ::subxt::Metadata,
+                marker: ::core::marker::PhantomData<T>,
             }
             impl<'a, T: ::subxt::Config> ConstantsApi<'a, T> {
                 pub fn new(client: &'a ::subxt::Client<T>) -> Self {
-                    Self { client }
+                    Self::from_metadata(client.metadata())
+                }
+            }
+            impl<'a, T> ConstantsApi<'a, T> {
+                #[doc = r" Read constants straight from `metadata`, without a client to fetch it with."]
+                #[doc = r""]
+                #[doc = r" `T` is only needed by [`Self::new`], so any type will do here."]
+                pub fn from_metadata(metadata: &'a ::subxt::Metadata) -> Self {
+                    Self {
+                        metadata,
+                        marker: ::core::marker::PhantomData,
+                    }
                 }
+                #[doc = " Fraction of a proposal's value that should be bonded in order to place the proposal."]
+                #[doc = " An accepted proposal gets these back. A rejected proposal does not."]
                 pub fn proposal_bond(
                     &self,
+                ) -> ::core::result::Result<ProposalBondTy, ::subxt::BasicError>
+                {
+                    let pallet = self.metadata.pallet("Treasury")?;
+                    let value =
+                        pallet.decode_constant::<ProposalBondTy>("ProposalBond")?;
+                    Ok(value)
+                }
+                pub fn proposal_bond_inner(
+                    &self,
+                ) -> ::core::result::Result<::core::primitive::u32, ::subxt::BasicError>
+                {
+                    let pallet = self.metadata.pallet("Treasury")?;
+                    let value =
+                        pallet.decode_constant::<ProposalBondTy>("ProposalBond")?;
+                    Ok(value.0)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn proposal_bond_raw(
+                    &self,
                 ) -> ::core::result::Result<
-                    runtime_types::sp_arithmetic::per_things::Permill,
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
                     ::subxt::BasicError,
                 > {
-                    let pallet = self.client.metadata().pallet("Treasury")?;
-                    let constant = pallet.constant("ProposalBond")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("Treasury")?;
+                    let value = pallet.raw_constant("ProposalBond")?;
                     Ok(value)
                 }
+                #[doc = " Minimum amount of funds that should be placed in a deposit for making a proposal."]
                 pub fn proposal_bond_minimum(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u128, ::subxt::BasicError>
+                ) -> ::core::result::Result<ProposalBondMinimumTy, ::subxt::BasicError>
                 {
-                    let pallet = self.client.metadata().pallet("Treasury")?;
-                    let constant = pallet.constant("ProposalBondMinimum")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("Treasury")?;
+                    let value = pallet.decode_constant::<ProposalBondMinimumTy>(
+                        "ProposalBondMinimum",
+                    )?;
+                    Ok(value)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn proposal_bond_minimum_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Treasury")?;
+                    let value = pallet.raw_constant("ProposalBondMinimum")?;
                     Ok(value)
                 }
+                #[doc = " Maximum amount of funds that should be placed in a deposit for making a proposal."]
                 pub fn proposal_bond_maximum(
                     &self,
+                ) -> ::core::result::Result<ProposalBondMaximumTy, ::subxt::BasicError>
+                {
+                    let pallet = self.metadata.pallet("Treasury")?;
+                    let value = pallet.decode_constant::<ProposalBondMaximumTy>(
+                        "ProposalBondMaximum",
+                    )?;
+                    Ok(value)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn proposal_bond_maximum_raw(
+                    &self,
                 ) -> ::core::result::Result<
-                    ::core::option::Option<::core::primitive::u128>,
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
                     ::subxt::BasicError,
                 > {
-                    let pallet = self.client.metadata().pallet("Treasury")?;
-                    let constant = pallet.constant("ProposalBondMaximum")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("Treasury")?;
+                    let value = pallet.raw_constant("ProposalBondMaximum")?;
                     Ok(value)
                 }
+                #[doc = " Period between successive spends."]
                 pub fn spend_period(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u32, ::subxt::BasicError>
+                ) -> ::core::result::Result<SpendPeriodTy, ::subxt::BasicError>
                 {
-                    let pallet = self.client.metadata().pallet("Treasury")?;
-                    let constant = pallet.constant("SpendPeriod")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("Treasury")?;
+                    let value = pallet.decode_constant::<SpendPeriodTy>("SpendPeriod")?;
                     Ok(value)
                 }
-                pub fn burn(
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn spend_period_raw(
                     &self,
                 ) -> ::core::result::Result<
-                    runtime_types::sp_arithmetic::per_things::Permill,
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
                     ::subxt::BasicError,
                 > {
-                    let pallet = self.client.metadata().pallet("Treasury")?;
-                    let constant = pallet.constant("Burn")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("Treasury")?;
+                    let value = pallet.raw_constant("SpendPeriod")?;
                     Ok(value)
                 }
-                pub fn pallet_id(
+                #[doc = " Percentage of spare funds (if any) that are burnt per spend period."]
+                pub fn burn(
+                    &self,
+                ) -> ::core::result::Result<BurnTy, ::subxt::BasicError> {
+                    let pallet = self.metadata.pallet("Treasury")?;
+                    let value = pallet.decode_constant::<BurnTy>("Burn")?;
+                    Ok(value)
+                }
+                pub fn burn_inner(
+                    &self,
+                ) -> ::core::result::Result<::core::primitive::u32, ::subxt::BasicError>
+                {
+                    let pallet = self.metadata.pallet("Treasury")?;
+                    let value = pallet.decode_constant::<BurnTy>("Burn")?;
+                    Ok(value.0)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn burn_raw(
                     &self,
                 ) -> ::core::result::Result<
-                    runtime_types::frame_support::PalletId,
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
                     ::subxt::BasicError,
                 > {
-                    let pallet = self.client.metadata().pallet("Treasury")?;
-                    let constant = pallet.constant("PalletId")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("Treasury")?;
+                    let value = pallet.raw_constant("Burn")?;
                     Ok(value)
                 }
-                pub fn max_approvals(
+                #[doc = " The treasury's pallet id, used for deriving its sovereign account ID."]
+                pub fn pallet_id(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u32, ::subxt::BasicError>
+                ) -> ::core::result::Result<PalletIdTy, ::subxt::BasicError>
                 {
-                    let pallet = self.client.metadata().pallet("Treasury")?;
-                    let constant = pallet.constant("MaxApprovals")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("Treasury")?;
+                    let value = pallet.decode_constant::<PalletIdTy>("PalletId")?;
                     Ok(value)
                 }
-            }
-        }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn pallet_id_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Treasury")?;
+                    let value = pallet.raw_constant("PalletId")?;
+                    Ok(value)
+                }
+                #[doc = " The maximum number of approvals that can wait in the spending queue."]
+                #[doc = ""]
+                #[doc = " NOTE: This parameter is also used within the Bounties Pallet extension if enabled."]
+                pub fn max_approvals(
+                    &self,
+                ) -> ::core::result::Result<MaxApprovalsTy, ::subxt::BasicError>
+                {
+                    let pallet = self.metadata.pallet("Treasury")?;
+                    let value =
+                        pallet.decode_constant::<MaxApprovalsTy>("MaxApprovals")?;
+                    Ok(value)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn max_approvals_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Treasury")?;
+                    let value = pallet.raw_constant("MaxApprovals")?;
+                    Ok(value)
+                }
+                #[doc = r" Returns the name and raw SCALE encoded value of every constant in this pallet."]
+                pub fn all(
+                    &self,
+                ) -> ::core::result::Result<
+                    ::std::vec::Vec<(
+                        &'static str,
+                        ::std::vec::Vec<::core::primitive::u8>,
+                    )>,
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Treasury")?;
+                    let mut constants = ::std::vec::Vec::new();
+                    constants.push((
+                        "ProposalBond",
+                        pallet.constant("ProposalBond")?.value.clone(),
+                    ));
+                    constants.push((
+                        "ProposalBondMinimum",
+                        pallet.constant("ProposalBondMinimum")?.value.clone(),
+                    ));
+                    constants.push((
+                        "ProposalBondMaximum",
+                        pallet.constant("ProposalBondMaximum")?.value.clone(),
+                    ));
+                    constants.push((
+                        "SpendPeriod",
+                        pallet.constant("SpendPeriod")?.value.clone(),
+                    ));
+                    constants.push(("Burn", pallet.constant("Burn")?.value.clone()));
+                    constants
+                        .push(("PalletId", pallet.constant("PalletId")?.value.clone()));
+                    constants.push((
+                        "MaxApprovals",
+                        pallet.constant("MaxApprovals")?.value.clone(),
+                    ));
+                    Ok(constants)
+                }
+            }
+        }
     }
     pub mod claims {
         use super::root_mod;
@@ -9657,24 +11130,64 @@ pub mod api {
         }
         pub mod constants {
             use super::runtime_types;
-            pub struct ConstantsApi<'a, T: ::subxt::Config> {
-                client: &'a ::subxt::Client<T>,
+            pub type PrefixTy = ::std::vec::Vec<::core::primitive::u8>;
+            pub struct ConstantsApi<'a, T> {
+                metadata: &'a ::subxt::Metadata,
+                marker: ::core::marker::PhantomData<T>,
             }
             impl<'a, T: ::subxt::Config> ConstantsApi<'a, T> {
                 pub fn new(client: &'a ::subxt::Client<T>) -> Self {
-                    Self { client }
+                    Self::from_metadata(client.metadata())
+                }
+            }
+            impl<'a, T> ConstantsApi<'a, T> {
+                #[doc = r" Read constants straight from `metadata`, without a client to fetch it with."]
+                #[doc = r""]
+                #[doc = r" `T` is only needed by [`Self::new`], so any type will do here."]
+                pub fn from_metadata(metadata: &'a ::subxt::Metadata) -> Self {
+                    Self {
+                        metadata,
+                        marker: ::core::marker::PhantomData,
+                    }
                 }
+                #[doc = " The `Claims::Prefix` constant, of type `::std::vec::Vec<::core::primitive::u8>`."]
                 pub fn prefix(
                     &self,
+                ) -> ::core::result::Result<PrefixTy, ::subxt::BasicError>
+                {
+                    let pallet = self.metadata.pallet("Claims")?;
+                    let value = pallet.decode_constant::<PrefixTy>("Prefix")?;
+                    Ok(value)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn prefix_raw(
+                    &self,
                 ) -> ::core::result::Result<
-                    ::std::vec::Vec<::core::primitive::u8>,
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
                     ::subxt::BasicError,
                 > {
-                    let pallet = self.client.metadata().pallet("Claims")?;
-                    let constant = pallet.constant("Prefix")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("Claims")?;
+                    let value = pallet.raw_constant("Prefix")?;
                     Ok(value)
                 }
+                #[doc = r" Returns the name and raw SCALE encoded value of every constant in this pallet."]
+                pub fn all(
+                    &self,
+                ) -> ::core::result::Result<
+                    ::std::vec::Vec<(
+                        &'static str,
+                        ::std::vec::Vec<::core::primitive::u8>,
+                    )>,
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Claims")?;
+                    let mut constants = ::std::vec::Vec::new();
+                    constants.push(("Prefix", pallet.constant("Prefix")?.value.clone()));
+                    Ok(constants)
+                }
             }
         }
     }
@@ -9958,31 +11471,98 @@ pub mod api {
         }
         pub mod constants {
             use super::runtime_types;
-            pub struct ConstantsApi<'a, T: ::subxt::Config> {
-                client: &'a ::subxt::Client<T>,
+            pub type MinVestedTransferTy = ::core::primitive::u128;
+            pub type MaxVestingSchedulesTy = ::core::primitive::u32;
+            pub struct ConstantsApi<'a, T> {
+                metadata: &'a ::subxt::Metadata,
+                marker: ::core::marker::PhantomData<T>,
             }
             impl<'a, T: ::subxt::Config> ConstantsApi<'a, T> {
                 pub fn new(client: &'a ::subxt::Client<T>) -> Self {
-                    Self { client }
+                    Self::from_metadata(client.metadata())
+                }
+            }
+            impl<'a, T> ConstantsApi<'a, T> {
+                #[doc = r" Read constants straight from `metadata`, without a client to fetch it with."]
+                #[doc = r""]
+                #[doc = r" `T` is only needed by [`Self::new`], so any type will do here."]
+                pub fn from_metadata(metadata: &'a ::subxt::Metadata) -> Self {
+                    Self {
+                        metadata,
+                        marker: ::core::marker::PhantomData,
+                    }
                 }
+                #[doc = " The minimum amount transferred to call `vested_transfer`."]
                 pub fn min_vested_transfer(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u128, ::subxt::BasicError>
+                ) -> ::core::result::Result<MinVestedTransferTy, ::subxt::BasicError>
                 {
-                    let pallet = self.client.metadata().pallet("Vesting")?;
-                    let constant = pallet.constant("MinVestedTransfer")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("Vesting")?;
+                    let value = pallet
+                        .decode_constant::<MinVestedTransferTy>("MinVestedTransfer")?;
+                    Ok(value)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn min_vested_transfer_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Vesting")?;
+                    let value = pallet.raw_constant("MinVestedTransfer")?;
                     Ok(value)
                 }
+                #[doc = " The `Vesting::MaxVestingSchedules` constant, of type `::core::primitive::u32`."]
                 pub fn max_vesting_schedules(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u32, ::subxt::BasicError>
+                ) -> ::core::result::Result<MaxVestingSchedulesTy, ::subxt::BasicError>
                 {
-                    let pallet = self.client.metadata().pallet("Vesting")?;
-                    let constant = pallet.constant("MaxVestingSchedules")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("Vesting")?;
+                    let value = pallet.decode_constant::<MaxVestingSchedulesTy>(
+                        "MaxVestingSchedules",
+                    )?;
+                    Ok(value)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn max_vesting_schedules_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Vesting")?;
+                    let value = pallet.raw_constant("MaxVestingSchedules")?;
                     Ok(value)
                 }
+                #[doc = r" Returns the name and raw SCALE encoded value of every constant in this pallet."]
+                pub fn all(
+                    &self,
+                ) -> ::core::result::Result<
+                    ::std::vec::Vec<(
+                        &'static str,
+                        ::std::vec::Vec<::core::primitive::u8>,
+                    )>,
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Vesting")?;
+                    let mut constants = ::std::vec::Vec::new();
+                    constants.push((
+                        "MinVestedTransfer",
+                        pallet.constant("MinVestedTransfer")?.value.clone(),
+                    ));
+                    constants.push((
+                        "MaxVestingSchedules",
+                        pallet.constant("MaxVestingSchedules")?.value.clone(),
+                    ));
+                    Ok(constants)
+                }
             }
         }
     }
@@ -10145,22 +11725,68 @@ pub mod api {
         }
         pub mod constants {
             use super::runtime_types;
-            pub struct ConstantsApi<'a, T: ::subxt::Config> {
-                client: &'a ::subxt::Client<T>,
+            pub type BatchedCallsLimitTy = ::core::primitive::u32;
+            pub struct ConstantsApi<'a, T> {
+                metadata: &'a ::subxt::Metadata,
+                marker: ::core::marker::PhantomData<T>,
             }
             impl<'a, T: ::subxt::Config> ConstantsApi<'a, T> {
                 pub fn new(client: &'a ::subxt::Client<T>) -> Self {
-                    Self { client }
+                    Self::from_metadata(client.metadata())
+                }
+            }
+            impl<'a, T> ConstantsApi<'a, T> {
+                #[doc = r" Read constants straight from `metadata`, without a client to fetch it with."]
+                #[doc = r""]
+                #[doc = r" `T` is only needed by [`Self::new`], so any type will do here."]
+                pub fn from_metadata(metadata: &'a ::subxt::Metadata) -> Self {
+                    Self {
+                        metadata,
+                        marker: ::core::marker::PhantomData,
+                    }
                 }
+                #[doc = " The limit on the number of batched calls."]
                 pub fn batched_calls_limit(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u32, ::subxt::BasicError>
+                ) -> ::core::result::Result<BatchedCallsLimitTy, ::subxt::BasicError>
                 {
-                    let pallet = self.client.metadata().pallet("Utility")?;
-                    let constant = pallet.constant("batched_calls_limit")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("Utility")?;
+                    let value = pallet
+                        .decode_constant::<BatchedCallsLimitTy>("batched_calls_limit")?;
+                    Ok(value)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn batched_calls_limit_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Utility")?;
+                    let value = pallet.raw_constant("batched_calls_limit")?;
                     Ok(value)
                 }
+                #[doc = r" Returns the name and raw SCALE encoded value of every constant in this pallet."]
+                pub fn all(
+                    &self,
+                ) -> ::core::result::Result<
+                    ::std::vec::Vec<(
+                        &'static str,
+                        ::std::vec::Vec<::core::primitive::u8>,
+                    )>,
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Utility")?;
+                    let mut constants = ::std::vec::Vec::new();
+                    constants.push((
+                        "batched_calls_limit",
+                        pallet.constant("batched_calls_limit")?.value.clone(),
+                    ));
+                    Ok(constants)
+                }
             }
         }
     }
@@ -10857,67 +12483,218 @@ pub mod api {
         }
         pub mod constants {
             use super::runtime_types;
-            pub struct ConstantsApi<'a, T: ::subxt::Config> {
-                client: &'a ::subxt::Client<T>,
+            pub type BasicDepositTy = ::core::primitive::u128;
+            pub type FieldDepositTy = ::core::primitive::u128;
+            pub type SubAccountDepositTy = ::core::primitive::u128;
+            pub type MaxSubAccountsTy = ::core::primitive::u32;
+            pub type MaxAdditionalFieldsTy = ::core::primitive::u32;
+            pub type MaxRegistrarsTy = ::core::primitive::u32;
+            pub struct ConstantsApi<'a, T> {
+                metadata: &'a ::subxt::Metadata,
+                marker: ::core::marker::PhantomData<T>,
             }
             impl<'a, T: ::subxt::Config> ConstantsApi<'a, T> {
                 pub fn new(client: &'a ::subxt::Client<T>) -> Self {
-                    Self { client }
+                    Self::from_metadata(client.metadata())
+                }
+            }
+            impl<'a, T> ConstantsApi<'a, T> {
+                #[doc = r" Read constants straight from `metadata`, without a client to fetch it with."]
+                #[doc = r""]
+                #[doc = r" `T` is only needed by [`Self::new`], so any type will do here."]
+                pub fn from_metadata(metadata: &'a ::subxt::Metadata) -> Self {
+                    Self {
+                        metadata,
+                        marker: ::core::marker::PhantomData,
+                    }
                 }
+                #[doc = " The amount held on deposit for a registered identity"]
                 pub fn basic_deposit(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u128, ::subxt::BasicError>
+                ) -> ::core::result::Result<BasicDepositTy, ::subxt::BasicError>
                 {
-                    let pallet = self.client.metadata().pallet("Identity")?;
-                    let constant = pallet.constant("BasicDeposit")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("Identity")?;
+                    let value =
+                        pallet.decode_constant::<BasicDepositTy>("BasicDeposit")?;
+                    Ok(value)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn basic_deposit_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Identity")?;
+                    let value = pallet.raw_constant("BasicDeposit")?;
                     Ok(value)
                 }
+                #[doc = " The amount held on deposit per additional field for a registered identity."]
                 pub fn field_deposit(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u128, ::subxt::BasicError>
+                ) -> ::core::result::Result<FieldDepositTy, ::subxt::BasicError>
                 {
-                    let pallet = self.client.metadata().pallet("Identity")?;
-                    let constant = pallet.constant("FieldDeposit")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("Identity")?;
+                    let value =
+                        pallet.decode_constant::<FieldDepositTy>("FieldDeposit")?;
+                    Ok(value)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn field_deposit_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Identity")?;
+                    let value = pallet.raw_constant("FieldDeposit")?;
                     Ok(value)
                 }
+                #[doc = " The amount held on deposit for a registered subaccount. This should account for the fact"]
+                #[doc = " that one storage item's value will increase by the size of an account ID, and there will"]
+                #[doc = " be another trie item whose value is the size of an account ID plus 32 bytes."]
                 pub fn sub_account_deposit(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u128, ::subxt::BasicError>
+                ) -> ::core::result::Result<SubAccountDepositTy, ::subxt::BasicError>
                 {
-                    let pallet = self.client.metadata().pallet("Identity")?;
-                    let constant = pallet.constant("SubAccountDeposit")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("Identity")?;
+                    let value = pallet
+                        .decode_constant::<SubAccountDepositTy>("SubAccountDeposit")?;
+                    Ok(value)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn sub_account_deposit_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Identity")?;
+                    let value = pallet.raw_constant("SubAccountDeposit")?;
                     Ok(value)
                 }
+                #[doc = " The maximum number of sub-accounts allowed per identified account."]
                 pub fn max_sub_accounts(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u32, ::subxt::BasicError>
+                ) -> ::core::result::Result<MaxSubAccountsTy, ::subxt::BasicError>
                 {
-                    let pallet = self.client.metadata().pallet("Identity")?;
-                    let constant = pallet.constant("MaxSubAccounts")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("Identity")?;
+                    let value =
+                        pallet.decode_constant::<MaxSubAccountsTy>("MaxSubAccounts")?;
+                    Ok(value)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn max_sub_accounts_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Identity")?;
+                    let value = pallet.raw_constant("MaxSubAccounts")?;
                     Ok(value)
                 }
+                #[doc = " Maximum number of additional fields that may be stored in an ID. Needed to bound the I/O"]
+                #[doc = " required to access an identity, but can be pretty high."]
                 pub fn max_additional_fields(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u32, ::subxt::BasicError>
+                ) -> ::core::result::Result<MaxAdditionalFieldsTy, ::subxt::BasicError>
                 {
-                    let pallet = self.client.metadata().pallet("Identity")?;
-                    let constant = pallet.constant("MaxAdditionalFields")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("Identity")?;
+                    let value = pallet.decode_constant::<MaxAdditionalFieldsTy>(
+                        "MaxAdditionalFields",
+                    )?;
+                    Ok(value)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn max_additional_fields_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Identity")?;
+                    let value = pallet.raw_constant("MaxAdditionalFields")?;
                     Ok(value)
                 }
+                #[doc = " Maxmimum number of registrars allowed in the system. Needed to bound the complexity"]
+                #[doc = " of, e.g., updating judgements."]
                 pub fn max_registrars(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u32, ::subxt::BasicError>
+                ) -> ::core::result::Result<MaxRegistrarsTy, ::subxt::BasicError>
                 {
-                    let pallet = self.client.metadata().pallet("Identity")?;
-                    let constant = pallet.constant("MaxRegistrars")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("Identity")?;
+                    let value =
+                        pallet.decode_constant::<MaxRegistrarsTy>("MaxRegistrars")?;
+                    Ok(value)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn max_registrars_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Identity")?;
+                    let value = pallet.raw_constant("MaxRegistrars")?;
                     Ok(value)
                 }
+                #[doc = r" Returns the name and raw SCALE encoded value of every constant in this pallet."]
+                pub fn all(
+                    &self,
+                ) -> ::core::result::Result<
+                    ::std::vec::Vec<(
+                        &'static str,
+                        ::std::vec::Vec<::core::primitive::u8>,
+                    )>,
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Identity")?;
+                    let mut constants = ::std::vec::Vec::new();
+                    constants.push((
+                        "BasicDeposit",
+                        pallet.constant("BasicDeposit")?.value.clone(),
+                    ));
+                    constants.push((
+                        "FieldDeposit",
+                        pallet.constant("FieldDeposit")?.value.clone(),
+                    ));
+                    constants.push((
+                        "SubAccountDeposit",
+                        pallet.constant("SubAccountDeposit")?.value.clone(),
+                    ));
+                    constants.push((
+                        "MaxSubAccounts",
+                        pallet.constant("MaxSubAccounts")?.value.clone(),
+                    ));
+                    constants.push((
+                        "MaxAdditionalFields",
+                        pallet.constant("MaxAdditionalFields")?.value.clone(),
+                    ));
+                    constants.push((
+                        "MaxRegistrars",
+                        pallet.constant("MaxRegistrars")?.value.clone(),
+                    ));
+                    Ok(constants)
+                }
             }
         }
     }
@@ -11405,67 +13182,228 @@ pub mod api {
         }
         pub mod constants {
             use super::runtime_types;
-            pub struct ConstantsApi<'a, T: ::subxt::Config> {
-                client: &'a ::subxt::Client<T>,
+            pub type ProxyDepositBaseTy = ::core::primitive::u128;
+            pub type ProxyDepositFactorTy = ::core::primitive::u128;
+            pub type MaxProxiesTy = ::core::primitive::u32;
+            pub type MaxPendingTy = ::core::primitive::u32;
+            pub type AnnouncementDepositBaseTy = ::core::primitive::u128;
+            pub type AnnouncementDepositFactorTy = ::core::primitive::u128;
+            pub struct ConstantsApi<'a, T> {
+                metadata: &'a ::subxt::Metadata,
+                marker: ::core::marker::PhantomData<T>,
             }
             impl<'a, T: ::subxt::Config> ConstantsApi<'a, T> {
                 pub fn new(client: &'a ::subxt::Client<T>) -> Self {
-                    Self { client }
+                    Self::from_metadata(client.metadata())
+                }
+            }
+            impl<'a, T> ConstantsApi<'a, T> {
+                #[doc = r" Read constants straight from `metadata`, without a client to fetch it with."]
+                #[doc = r""]
+                #[doc = r" `T` is only needed by [`Self::new`], so any type will do here."]
+                pub fn from_metadata(metadata: &'a ::subxt::Metadata) -> Self {
+                    Self {
+                        metadata,
+                        marker: ::core::marker::PhantomData,
+                    }
                 }
+                #[doc = " The base amount of currency needed to reserve for creating a proxy."]
+                #[doc = ""]
+                #[doc = " This is held for an additional storage item whose value size is"]
+                #[doc = " `sizeof(Balance)` bytes and whose key size is `sizeof(AccountId)` bytes."]
                 pub fn proxy_deposit_base(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u128, ::subxt::BasicError>
+                ) -> ::core::result::Result<ProxyDepositBaseTy, ::subxt::BasicError>
                 {
-                    let pallet = self.client.metadata().pallet("Proxy")?;
-                    let constant = pallet.constant("ProxyDepositBase")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("Proxy")?;
+                    let value = pallet
+                        .decode_constant::<ProxyDepositBaseTy>("ProxyDepositBase")?;
+                    Ok(value)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn proxy_deposit_base_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Proxy")?;
+                    let value = pallet.raw_constant("ProxyDepositBase")?;
                     Ok(value)
                 }
+                #[doc = " The amount of currency needed per proxy added."]
+                #[doc = ""]
+                #[doc = " This is held for adding 32 bytes plus an instance of `ProxyType` more into a"]
+                #[doc = " pre-existing storage value. Thus, when configuring `ProxyDepositFactor` one should take"]
+                #[doc = " into account `32 + proxy_type.encode().len()` bytes of data."]
                 pub fn proxy_deposit_factor(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u128, ::subxt::BasicError>
+                ) -> ::core::result::Result<ProxyDepositFactorTy, ::subxt::BasicError>
                 {
-                    let pallet = self.client.metadata().pallet("Proxy")?;
-                    let constant = pallet.constant("ProxyDepositFactor")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("Proxy")?;
+                    let value = pallet
+                        .decode_constant::<ProxyDepositFactorTy>("ProxyDepositFactor")?;
+                    Ok(value)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn proxy_deposit_factor_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Proxy")?;
+                    let value = pallet.raw_constant("ProxyDepositFactor")?;
                     Ok(value)
                 }
+                #[doc = " The maximum amount of proxies allowed for a single account."]
                 pub fn max_proxies(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u32, ::subxt::BasicError>
+                ) -> ::core::result::Result<MaxProxiesTy, ::subxt::BasicError>
                 {
-                    let pallet = self.client.metadata().pallet("Proxy")?;
-                    let constant = pallet.constant("MaxProxies")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("Proxy")?;
+                    let value = pallet.decode_constant::<MaxProxiesTy>("MaxProxies")?;
+                    Ok(value)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn max_proxies_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Proxy")?;
+                    let value = pallet.raw_constant("MaxProxies")?;
                     Ok(value)
                 }
+                #[doc = " The maximum amount of time-delayed announcements that are allowed to be pending."]
                 pub fn max_pending(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u32, ::subxt::BasicError>
+                ) -> ::core::result::Result<MaxPendingTy, ::subxt::BasicError>
                 {
-                    let pallet = self.client.metadata().pallet("Proxy")?;
-                    let constant = pallet.constant("MaxPending")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("Proxy")?;
+                    let value = pallet.decode_constant::<MaxPendingTy>("MaxPending")?;
+                    Ok(value)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn max_pending_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Proxy")?;
+                    let value = pallet.raw_constant("MaxPending")?;
                     Ok(value)
                 }
+                #[doc = " The base amount of currency needed to reserve for creating an announcement."]
+                #[doc = ""]
+                #[doc = " This is held when a new storage item holding a `Balance` is created (typically 16"]
+                #[doc = " bytes)."]
                 pub fn announcement_deposit_base(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u128, ::subxt::BasicError>
+                ) -> ::core::result::Result<AnnouncementDepositBaseTy, ::subxt::BasicError>
                 {
-                    let pallet = self.client.metadata().pallet("Proxy")?;
-                    let constant = pallet.constant("AnnouncementDepositBase")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("Proxy")?;
+                    let value = pallet.decode_constant::<AnnouncementDepositBaseTy>(
+                        "AnnouncementDepositBase",
+                    )?;
                     Ok(value)
                 }
-                pub fn announcement_deposit_factor(
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn announcement_deposit_base_raw(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u128, ::subxt::BasicError>
-                {
-                    let pallet = self.client.metadata().pallet("Proxy")?;
-                    let constant = pallet.constant("AnnouncementDepositFactor")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Proxy")?;
+                    let value = pallet.raw_constant("AnnouncementDepositBase")?;
+                    Ok(value)
+                }
+                #[doc = " The amount of currency needed per announcement made."]
+                #[doc = ""]
+                #[doc = " This is held for adding an `AccountId`, `Hash` and `BlockNumber` (typically 68 bytes)"]
+                #[doc = " into a pre-existing storage value."]
+                pub fn announcement_deposit_factor(
+                    &self,
+                ) -> ::core::result::Result<
+                    AnnouncementDepositFactorTy,
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Proxy")?;
+                    let value = pallet.decode_constant::<AnnouncementDepositFactorTy>(
+                        "AnnouncementDepositFactor",
+                    )?;
                     Ok(value)
                 }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn announcement_deposit_factor_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Proxy")?;
+                    let value = pallet.raw_constant("AnnouncementDepositFactor")?;
+                    Ok(value)
+                }
+                #[doc = r" Returns the name and raw SCALE encoded value of every constant in this pallet."]
+                pub fn all(
+                    &self,
+                ) -> ::core::result::Result<
+                    ::std::vec::Vec<(
+                        &'static str,
+                        ::std::vec::Vec<::core::primitive::u8>,
+                    )>,
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Proxy")?;
+                    let mut constants = ::std::vec::Vec::new();
+                    constants.push((
+                        "ProxyDepositBase",
+                        pallet.constant("ProxyDepositBase")?.value.clone(),
+                    ));
+                    constants.push((
+                        "ProxyDepositFactor",
+                        pallet.constant("ProxyDepositFactor")?.value.clone(),
+                    ));
+                    constants.push((
+                        "MaxProxies",
+                        pallet.constant("MaxProxies")?.value.clone(),
+                    ));
+                    constants.push((
+                        "MaxPending",
+                        pallet.constant("MaxPending")?.value.clone(),
+                    ));
+                    constants.push((
+                        "AnnouncementDepositBase",
+                        pallet.constant("AnnouncementDepositBase")?.value.clone(),
+                    ));
+                    constants.push((
+                        "AnnouncementDepositFactor",
+                        pallet.constant("AnnouncementDepositFactor")?.value.clone(),
+                    ));
+                    Ok(constants)
+                }
             }
         }
     }
@@ -11811,40 +13749,132 @@ pub mod api {
         }
         pub mod constants {
             use super::runtime_types;
-            pub struct ConstantsApi<'a, T: ::subxt::Config> {
-                client: &'a ::subxt::Client<T>,
+            pub type DepositBaseTy = ::core::primitive::u128;
+            pub type DepositFactorTy = ::core::primitive::u128;
+            pub type MaxSignatoriesTy = ::core::primitive::u16;
+            pub struct ConstantsApi<'a, T> {
+                metadata: &'a ::subxt::Metadata,
+                marker: ::core::marker::PhantomData<T>,
             }
             impl<'a, T: ::subxt::Config> ConstantsApi<'a, T> {
                 pub fn new(client: &'a ::subxt::Client<T>) -> Self {
-                    Self { client }
+                    Self::from_metadata(client.metadata())
                 }
+            }
+            impl<'a, T> ConstantsApi<'a, T> {
+                #[doc = r" Read constants straight from `metadata`, without a client to fetch it with."]
+                #[doc = r""]
+                #[doc = r" `T` is only needed by [`Self::new`], so any type will do here."]
+                pub fn from_metadata(metadata: &'a ::subxt::Metadata) -> Self {
+                    Self {
+                        metadata,
+                        marker: ::core::marker::PhantomData,
+                    }
+                }
+                #[doc = " The base amount of currency needed to reserve for creating a multisig execution or to"]
+                #[doc = " store a dispatch call for later."]
+                #[doc = ""]
+                #[doc = " This is held for an additional storage item whose value size is"]
+                #[doc = " `4 + sizeof((BlockNumber, Balance, AccountId))` bytes and whose key size is"]
+                #[doc = " `32 + sizeof(AccountId)` bytes."]
                 pub fn deposit_base(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u128, ::subxt::BasicError>
+                ) -> ::core::result::Result<DepositBaseTy, ::subxt::BasicError>
                 {
-                    let pallet = self.client.metadata().pallet("Multisig")?;
-                    let constant = pallet.constant("DepositBase")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("Multisig")?;
+                    let value = pallet.decode_constant::<DepositBaseTy>("DepositBase")?;
+                    Ok(value)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn deposit_base_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Multisig")?;
+                    let value = pallet.raw_constant("DepositBase")?;
                     Ok(value)
                 }
+                #[doc = " The amount of currency needed per unit threshold when creating a multisig execution."]
+                #[doc = ""]
+                #[doc = " This is held for adding 32 bytes more into a pre-existing storage value."]
                 pub fn deposit_factor(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u128, ::subxt::BasicError>
+                ) -> ::core::result::Result<DepositFactorTy, ::subxt::BasicError>
                 {
-                    let pallet = self.client.metadata().pallet("Multisig")?;
-                    let constant = pallet.constant("DepositFactor")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("Multisig")?;
+                    let value =
+                        pallet.decode_constant::<DepositFactorTy>("DepositFactor")?;
                     Ok(value)
                 }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn deposit_factor_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Multisig")?;
+                    let value = pallet.raw_constant("DepositFactor")?;
+                    Ok(value)
+                }
+                #[doc = " The maximum amount of signatories allowed in the multisig."]
                 pub fn max_signatories(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u16, ::subxt::BasicError>
+                ) -> ::core::result::Result<MaxSignatoriesTy, ::subxt::BasicError>
                 {
-                    let pallet = self.client.metadata().pallet("Multisig")?;
-                    let constant = pallet.constant("MaxSignatories")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("Multisig")?;
+                    let value =
+                        pallet.decode_constant::<MaxSignatoriesTy>("MaxSignatories")?;
                     Ok(value)
                 }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn max_signatories_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Multisig")?;
+                    let value = pallet.raw_constant("MaxSignatories")?;
+                    Ok(value)
+                }
+                #[doc = r" Returns the name and raw SCALE encoded value of every constant in this pallet."]
+                pub fn all(
+                    &self,
+                ) -> ::core::result::Result<
+                    ::std::vec::Vec<(
+                        &'static str,
+                        ::std::vec::Vec<::core::primitive::u8>,
+                    )>,
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Multisig")?;
+                    let mut constants = ::std::vec::Vec::new();
+                    constants.push((
+                        "DepositBase",
+                        pallet.constant("DepositBase")?.value.clone(),
+                    ));
+                    constants.push((
+                        "DepositFactor",
+                        pallet.constant("DepositFactor")?.value.clone(),
+                    ));
+                    constants.push((
+                        "MaxSignatories",
+                        pallet.constant("MaxSignatories")?.value.clone(),
+                    ));
+                    Ok(constants)
+                }
             }
         }
     }
@@ -12328,100 +14358,321 @@ pub mod api {
         }
         pub mod constants {
             use super::runtime_types;
-            pub struct ConstantsApi<'a, T: ::subxt::Config> {
-                client: &'a ::subxt::Client<T>,
+            pub type BountyDepositBaseTy = ::core::primitive::u128;
+            pub type BountyDepositPayoutDelayTy = ::core::primitive::u32;
+            pub type BountyUpdatePeriodTy = ::core::primitive::u32;
+            pub type CuratorDepositMultiplierTy =
+                runtime_types::sp_arithmetic::per_things::Permill;
+            pub type CuratorDepositMaxTy =
+                ::core::option::Option<::core::primitive::u128>;
+            pub type CuratorDepositMinTy =
+                ::core::option::Option<::core::primitive::u128>;
+            pub type BountyValueMinimumTy = ::core::primitive::u128;
+            pub type DataDepositPerByteTy = ::core::primitive::u128;
+            pub type MaximumReasonLengthTy = ::core::primitive::u32;
+            pub struct ConstantsApi<'a, T> {
+                metadata: &'a ::subxt::Metadata,
+                marker: ::core::marker::PhantomData<T>,
             }
             impl<'a, T: ::subxt::Config> ConstantsApi<'a, T> {
                 pub fn new(client: &'a ::subxt::Client<T>) -> Self {
-                    Self { client }
+                    Self::from_metadata(client.metadata())
                 }
+            }
+            impl<'a, T> ConstantsApi<'a, T> {
+                #[doc = r" Read constants straight from `metadata`, without a client to fetch it with."]
+                #[doc = r""]
+                #[doc = r" `T` is only needed by [`Self::new`], so any type will do here."]
+                pub fn from_metadata(metadata: &'a ::subxt::Metadata) -> Self {
+                    Self {
+                        metadata,
+                        marker: ::core::marker::PhantomData,
+                    }
+                }
+                #[doc = " The amount held on deposit for placing a bounty proposal."]
                 pub fn bounty_deposit_base(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u128, ::subxt::BasicError>
+                ) -> ::core::result::Result<BountyDepositBaseTy, ::subxt::BasicError>
                 {
-                    let pallet = self.client.metadata().pallet("Bounties")?;
-                    let constant = pallet.constant("BountyDepositBase")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("Bounties")?;
+                    let value = pallet
+                        .decode_constant::<BountyDepositBaseTy>("BountyDepositBase")?;
                     Ok(value)
                 }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn bounty_deposit_base_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Bounties")?;
+                    let value = pallet.raw_constant("BountyDepositBase")?;
+                    Ok(value)
+                }
+                #[doc = " The delay period for which a bounty beneficiary need to wait before claim the payout."]
                 pub fn bounty_deposit_payout_delay(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u32, ::subxt::BasicError>
+                ) -> ::core::result::Result<BountyDepositPayoutDelayTy, ::subxt::BasicError>
                 {
-                    let pallet = self.client.metadata().pallet("Bounties")?;
-                    let constant = pallet.constant("BountyDepositPayoutDelay")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("Bounties")?;
+                    let value = pallet.decode_constant::<BountyDepositPayoutDelayTy>(
+                        "BountyDepositPayoutDelay",
+                    )?;
                     Ok(value)
                 }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn bounty_deposit_payout_delay_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Bounties")?;
+                    let value = pallet.raw_constant("BountyDepositPayoutDelay")?;
+                    Ok(value)
+                }
+                #[doc = " Bounty duration in blocks."]
                 pub fn bounty_update_period(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u32, ::subxt::BasicError>
+                ) -> ::core::result::Result<BountyUpdatePeriodTy, ::subxt::BasicError>
                 {
-                    let pallet = self.client.metadata().pallet("Bounties")?;
-                    let constant = pallet.constant("BountyUpdatePeriod")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("Bounties")?;
+                    let value = pallet
+                        .decode_constant::<BountyUpdatePeriodTy>("BountyUpdatePeriod")?;
                     Ok(value)
                 }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn bounty_update_period_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Bounties")?;
+                    let value = pallet.raw_constant("BountyUpdatePeriod")?;
+                    Ok(value)
+                }
+                #[doc = " The curator deposit is calculated as a percentage of the curator fee."]
+                #[doc = ""]
+                #[doc = " This deposit has optional upper and lower bounds with `CuratorDepositMax` and"]
+                #[doc = " `CuratorDepositMin`."]
                 pub fn curator_deposit_multiplier(
                     &self,
+                ) -> ::core::result::Result<CuratorDepositMultiplierTy, ::subxt::BasicError>
+                {
+                    let pallet = self.metadata.pallet("Bounties")?;
+                    let value = pallet.decode_constant::<CuratorDepositMultiplierTy>(
+                        "CuratorDepositMultiplier",
+                    )?;
+                    Ok(value)
+                }
+                pub fn curator_deposit_multiplier_inner(
+                    &self,
+                ) -> ::core::result::Result<::core::primitive::u32, ::subxt::BasicError>
+                {
+                    let pallet = self.metadata.pallet("Bounties")?;
+                    let value = pallet.decode_constant::<CuratorDepositMultiplierTy>(
+                        "CuratorDepositMultiplier",
+                    )?;
+                    Ok(value.0)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn curator_deposit_multiplier_raw(
+                    &self,
                 ) -> ::core::result::Result<
-                    runtime_types::sp_arithmetic::per_things::Permill,
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
                     ::subxt::BasicError,
                 > {
-                    let pallet = self.client.metadata().pallet("Bounties")?;
-                    let constant = pallet.constant("CuratorDepositMultiplier")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("Bounties")?;
+                    let value = pallet.raw_constant("CuratorDepositMultiplier")?;
                     Ok(value)
                 }
+                #[doc = " Maximum amount of funds that should be placed in a deposit for making a proposal."]
                 pub fn curator_deposit_max(
                     &self,
+                ) -> ::core::result::Result<CuratorDepositMaxTy, ::subxt::BasicError>
+                {
+                    let pallet = self.metadata.pallet("Bounties")?;
+                    let value = pallet
+                        .decode_constant::<CuratorDepositMaxTy>("CuratorDepositMax")?;
+                    Ok(value)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn curator_deposit_max_raw(
+                    &self,
                 ) -> ::core::result::Result<
-                    ::core::option::Option<::core::primitive::u128>,
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
                     ::subxt::BasicError,
                 > {
-                    let pallet = self.client.metadata().pallet("Bounties")?;
-                    let constant = pallet.constant("CuratorDepositMax")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("Bounties")?;
+                    let value = pallet.raw_constant("CuratorDepositMax")?;
                     Ok(value)
                 }
+                #[doc = " Minimum amount of funds that should be placed in a deposit for making a proposal."]
                 pub fn curator_deposit_min(
                     &self,
+                ) -> ::core::result::Result<CuratorDepositMinTy, ::subxt::BasicError>
+                {
+                    let pallet = self.metadata.pallet("Bounties")?;
+                    let value = pallet
+                        .decode_constant::<CuratorDepositMinTy>("CuratorDepositMin")?;
+                    Ok(value)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn curator_deposit_min_raw(
+                    &self,
                 ) -> ::core::result::Result<
-                    ::core::option::Option<::core::primitive::u128>,
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
                     ::subxt::BasicError,
                 > {
-                    let pallet = self.client.metadata().pallet("Bounties")?;
-                    let constant = pallet.constant("CuratorDepositMin")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("Bounties")?;
+                    let value = pallet.raw_constant("CuratorDepositMin")?;
                     Ok(value)
                 }
+                #[doc = " Minimum value for a bounty."]
                 pub fn bounty_value_minimum(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u128, ::subxt::BasicError>
+                ) -> ::core::result::Result<BountyValueMinimumTy, ::subxt::BasicError>
                 {
-                    let pallet = self.client.metadata().pallet("Bounties")?;
-                    let constant = pallet.constant("BountyValueMinimum")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("Bounties")?;
+                    let value = pallet
+                        .decode_constant::<BountyValueMinimumTy>("BountyValueMinimum")?;
                     Ok(value)
                 }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn bounty_value_minimum_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Bounties")?;
+                    let value = pallet.raw_constant("BountyValueMinimum")?;
+                    Ok(value)
+                }
+                #[doc = " The amount held on deposit per byte within the tip report reason or bounty description."]
                 pub fn data_deposit_per_byte(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u128, ::subxt::BasicError>
+                ) -> ::core::result::Result<DataDepositPerByteTy, ::subxt::BasicError>
                 {
-                    let pallet = self.client.metadata().pallet("Bounties")?;
-                    let constant = pallet.constant("DataDepositPerByte")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("Bounties")?;
+                    let value = pallet
+                        .decode_constant::<DataDepositPerByteTy>("DataDepositPerByte")?;
                     Ok(value)
                 }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn data_deposit_per_byte_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Bounties")?;
+                    let value = pallet.raw_constant("DataDepositPerByte")?;
+                    Ok(value)
+                }
+                #[doc = " Maximum acceptable reason length."]
+                #[doc = ""]
+                #[doc = " Benchmarks depend on this value, be sure to update weights file when changing this value"]
                 pub fn maximum_reason_length(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u32, ::subxt::BasicError>
+                ) -> ::core::result::Result<MaximumReasonLengthTy, ::subxt::BasicError>
                 {
-                    let pallet = self.client.metadata().pallet("Bounties")?;
-                    let constant = pallet.constant("MaximumReasonLength")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("Bounties")?;
+                    let value = pallet.decode_constant::<MaximumReasonLengthTy>(
+                        "MaximumReasonLength",
+                    )?;
+                    Ok(value)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn maximum_reason_length_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Bounties")?;
+                    let value = pallet.raw_constant("MaximumReasonLength")?;
                     Ok(value)
                 }
+                #[doc = r" Returns the name and raw SCALE encoded value of every constant in this pallet."]
+                pub fn all(
+                    &self,
+                ) -> ::core::result::Result<
+                    ::std::vec::Vec<(
+                        &'static str,
+                        ::std::vec::Vec<::core::primitive::u8>,
+                    )>,
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Bounties")?;
+                    let mut constants = ::std::vec::Vec::new();
+                    constants.push((
+                        "BountyDepositBase",
+                        pallet.constant("BountyDepositBase")?.value.clone(),
+                    ));
+                    constants.push((
+                        "BountyDepositPayoutDelay",
+                        pallet.constant("BountyDepositPayoutDelay")?.value.clone(),
+                    ));
+                    constants.push((
+                        "BountyUpdatePeriod",
+                        pallet.constant("BountyUpdatePeriod")?.value.clone(),
+                    ));
+                    constants.push((
+                        "CuratorDepositMultiplier",
+                        pallet.constant("CuratorDepositMultiplier")?.value.clone(),
+                    ));
+                    constants.push((
+                        "CuratorDepositMax",
+                        pallet.constant("CuratorDepositMax")?.value.clone(),
+                    ));
+                    constants.push((
+                        "CuratorDepositMin",
+                        pallet.constant("CuratorDepositMin")?.value.clone(),
+                    ));
+                    constants.push((
+                        "BountyValueMinimum",
+                        pallet.constant("BountyValueMinimum")?.value.clone(),
+                    ));
+                    constants.push((
+                        "DataDepositPerByte",
+                        pallet.constant("DataDepositPerByte")?.value.clone(),
+                    ));
+                    constants.push((
+                        "MaximumReasonLength",
+                        pallet.constant("MaximumReasonLength")?.value.clone(),
+                    ));
+                    Ok(constants)
+                }
             }
         }
     }
@@ -12901,31 +15152,101 @@ pub mod api {
         }
         pub mod constants {
             use super::runtime_types;
-            pub struct ConstantsApi<'a, T: ::subxt::Config> {
-                client: &'a ::subxt::Client<T>,
+            pub type MaxActiveChildBountyCountTy = ::core::primitive::u32;
+            pub type ChildBountyValueMinimumTy = ::core::primitive::u128;
+            pub struct ConstantsApi<'a, T> {
+                metadata: &'a ::subxt::Metadata,
+                marker: ::core::marker::PhantomData<T>,
             }
             impl<'a, T: ::subxt::Config> ConstantsApi<'a, T> {
                 pub fn new(client: &'a ::subxt::Client<T>) -> Self {
-                    Self { client }
+                    Self::from_metadata(client.metadata())
+                }
+            }
+            impl<'a, T> ConstantsApi<'a, T> {
+                #[doc = r" Read constants straight from `metadata`, without a client to fetch it with."]
+                #[doc = r""]
+                #[doc = r" `T` is only needed by [`Self::new`], so any type will do here."]
+                pub fn from_metadata(metadata: &'a ::subxt::Metadata) -> Self {
+                    Self {
+                        metadata,
+                        marker: ::core::marker::PhantomData,
+                    }
                 }
+                #[doc = " Maximum number of child-bounties that can be added to a parent bounty."]
                 pub fn max_active_child_bounty_count(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u32, ::subxt::BasicError>
-                {
-                    let pallet = self.client.metadata().pallet("ChildBounties")?;
-                    let constant = pallet.constant("MaxActiveChildBountyCount")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                ) -> ::core::result::Result<
+                    MaxActiveChildBountyCountTy,
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("ChildBounties")?;
+                    let value = pallet.decode_constant::<MaxActiveChildBountyCountTy>(
+                        "MaxActiveChildBountyCount",
+                    )?;
                     Ok(value)
                 }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn max_active_child_bounty_count_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("ChildBounties")?;
+                    let value = pallet.raw_constant("MaxActiveChildBountyCount")?;
+                    Ok(value)
+                }
+                #[doc = " Minimum value for a child-bounty."]
                 pub fn child_bounty_value_minimum(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u128, ::subxt::BasicError>
+                ) -> ::core::result::Result<ChildBountyValueMinimumTy, ::subxt::BasicError>
                 {
-                    let pallet = self.client.metadata().pallet("ChildBounties")?;
-                    let constant = pallet.constant("ChildBountyValueMinimum")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("ChildBounties")?;
+                    let value = pallet.decode_constant::<ChildBountyValueMinimumTy>(
+                        "ChildBountyValueMinimum",
+                    )?;
                     Ok(value)
                 }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn child_bounty_value_minimum_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("ChildBounties")?;
+                    let value = pallet.raw_constant("ChildBountyValueMinimum")?;
+                    Ok(value)
+                }
+                #[doc = r" Returns the name and raw SCALE encoded value of every constant in this pallet."]
+                pub fn all(
+                    &self,
+                ) -> ::core::result::Result<
+                    ::std::vec::Vec<(
+                        &'static str,
+                        ::std::vec::Vec<::core::primitive::u8>,
+                    )>,
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("ChildBounties")?;
+                    let mut constants = ::std::vec::Vec::new();
+                    constants.push((
+                        "MaxActiveChildBountyCount",
+                        pallet.constant("MaxActiveChildBountyCount")?.value.clone(),
+                    ));
+                    constants.push((
+                        "ChildBountyValueMinimum",
+                        pallet.constant("ChildBountyValueMinimum")?.value.clone(),
+                    ));
+                    Ok(constants)
+                }
             }
         }
     }
@@ -13236,60 +15557,197 @@ pub mod api {
         }
         pub mod constants {
             use super::runtime_types;
-            pub struct ConstantsApi<'a, T: ::subxt::Config> {
-                client: &'a ::subxt::Client<T>,
+            pub type MaximumReasonLengthTy = ::core::primitive::u32;
+            pub type DataDepositPerByteTy = ::core::primitive::u128;
+            pub type TipCountdownTy = ::core::primitive::u32;
+            pub type TipFindersFeeTy = runtime_types::sp_arithmetic::per_things::Percent;
+            pub type TipReportDepositBaseTy = ::core::primitive::u128;
+            pub struct ConstantsApi<'a, T> {
+                metadata: &'a ::subxt::Metadata,
+                marker: ::core::marker::PhantomData<T>,
             }
             impl<'a, T: ::subxt::Config> ConstantsApi<'a, T> {
                 pub fn new(client: &'a ::subxt::Client<T>) -> Self {
-                    Self { client }
+                    Self::from_metadata(client.metadata())
                 }
+            }
+            impl<'a, T> ConstantsApi<'a, T> {
+                #[doc = r" Read constants straight from `metadata`, without a client to fetch it with."]
+                #[doc = r""]
+                #[doc = r" `T` is only needed by [`Self::new`], so any type will do here."]
+                pub fn from_metadata(metadata: &'a ::subxt::Metadata) -> Self {
+                    Self {
+                        metadata,
+                        marker: ::core::marker::PhantomData,
+                    }
+                }
+                #[doc = " Maximum acceptable reason length."]
+                #[doc = ""]
+                #[doc = " Benchmarks depend on this value, be sure to update weights file when changing this value"]
                 pub fn maximum_reason_length(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u32, ::subxt::BasicError>
+                ) -> ::core::result::Result<MaximumReasonLengthTy, ::subxt::BasicError>
                 {
-                    let pallet = self.client.metadata().pallet("Tips")?;
-                    let constant = pallet.constant("MaximumReasonLength")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("Tips")?;
+                    let value = pallet.decode_constant::<MaximumReasonLengthTy>(
+                        "MaximumReasonLength",
+                    )?;
                     Ok(value)
                 }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn maximum_reason_length_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Tips")?;
+                    let value = pallet.raw_constant("MaximumReasonLength")?;
+                    Ok(value)
+                }
+                #[doc = " The amount held on deposit per byte within the tip report reason or bounty description."]
                 pub fn data_deposit_per_byte(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u128, ::subxt::BasicError>
+                ) -> ::core::result::Result<DataDepositPerByteTy, ::subxt::BasicError>
                 {
-                    let pallet = self.client.metadata().pallet("Tips")?;
-                    let constant = pallet.constant("DataDepositPerByte")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("Tips")?;
+                    let value = pallet
+                        .decode_constant::<DataDepositPerByteTy>("DataDepositPerByte")?;
                     Ok(value)
                 }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn data_deposit_per_byte_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Tips")?;
+                    let value = pallet.raw_constant("DataDepositPerByte")?;
+                    Ok(value)
+                }
+                #[doc = " The period for which a tip remains open after is has achieved threshold tippers."]
                 pub fn tip_countdown(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u32, ::subxt::BasicError>
+                ) -> ::core::result::Result<TipCountdownTy, ::subxt::BasicError>
                 {
-                    let pallet = self.client.metadata().pallet("Tips")?;
-                    let constant = pallet.constant("TipCountdown")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("Tips")?;
+                    let value =
+                        pallet.decode_constant::<TipCountdownTy>("TipCountdown")?;
                     Ok(value)
                 }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn tip_countdown_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Tips")?;
+                    let value = pallet.raw_constant("TipCountdown")?;
+                    Ok(value)
+                }
+                #[doc = " The percent of the final tip which goes to the original reporter of the tip."]
                 pub fn tip_finders_fee(
                     &self,
+                ) -> ::core::result::Result<TipFindersFeeTy, ::subxt::BasicError>
+                {
+                    let pallet = self.metadata.pallet("Tips")?;
+                    let value =
+                        pallet.decode_constant::<TipFindersFeeTy>("TipFindersFee")?;
+                    Ok(value)
+                }
+                pub fn tip_finders_fee_inner(
+                    &self,
+                ) -> ::core::result::Result<::core::primitive::u8, ::subxt::BasicError>
+                {
+                    let pallet = self.metadata.pallet("Tips")?;
+                    let value =
+                        pallet.decode_constant::<TipFindersFeeTy>("TipFindersFee")?;
+                    Ok(value.0)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn tip_finders_fee_raw(
+                    &self,
                 ) -> ::core::result::Result<
-                    runtime_types::sp_arithmetic::per_things::Percent,
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
                     ::subxt::BasicError,
                 > {
-                    let pallet = self.client.metadata().pallet("Tips")?;
-                    let constant = pallet.constant("TipFindersFee")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("Tips")?;
+                    let value = pallet.raw_constant("TipFindersFee")?;
                     Ok(value)
                 }
+                #[doc = " The amount held on deposit for placing a tip report."]
                 pub fn tip_report_deposit_base(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u128, ::subxt::BasicError>
+                ) -> ::core::result::Result<TipReportDepositBaseTy, ::subxt::BasicError>
                 {
-                    let pallet = self.client.metadata().pallet("Tips")?;
-                    let constant = pallet.constant("TipReportDepositBase")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("Tips")?;
+                    let value = pallet.decode_constant::<TipReportDepositBaseTy>(
+                        "TipReportDepositBase",
+                    )?;
+                    Ok(value)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn tip_report_deposit_base_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Tips")?;
+                    let value = pallet.raw_constant("TipReportDepositBase")?;
                     Ok(value)
                 }
+                #[doc = r" Returns the name and raw SCALE encoded value of every constant in this pallet."]
+                pub fn all(
+                    &self,
+                ) -> ::core::result::Result<
+                    ::std::vec::Vec<(
+                        &'static str,
+                        ::std::vec::Vec<::core::primitive::u8>,
+                    )>,
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Tips")?;
+                    let mut constants = ::std::vec::Vec::new();
+                    constants.push((
+                        "MaximumReasonLength",
+                        pallet.constant("MaximumReasonLength")?.value.clone(),
+                    ));
+                    constants.push((
+                        "DataDepositPerByte",
+                        pallet.constant("DataDepositPerByte")?.value.clone(),
+                    ));
+                    constants.push((
+                        "TipCountdown",
+                        pallet.constant("TipCountdown")?.value.clone(),
+                    ));
+                    constants.push((
+                        "TipFindersFee",
+                        pallet.constant("TipFindersFee")?.value.clone(),
+                    ));
+                    constants.push((
+                        "TipReportDepositBase",
+                        pallet.constant("TipReportDepositBase")?.value.clone(),
+                    ));
+                    Ok(constants)
+                }
             }
         }
     }
@@ -13709,195 +16167,515 @@ pub mod api {
         }
         pub mod constants {
             use super::runtime_types;
-            pub struct ConstantsApi<'a, T: ::subxt::Config> {
-                client: &'a ::subxt::Client<T>,
+            pub type UnsignedPhaseTy = ::core::primitive::u32;
+            pub type SignedPhaseTy = ::core::primitive::u32;
+            pub type SolutionImprovementThresholdTy =
+                runtime_types::sp_arithmetic::per_things::Perbill;
+            pub type OffchainRepeatTy = ::core::primitive::u32;
+            pub type MinerTxPriorityTy = ::core::primitive::u64;
+            pub type MinerMaxWeightTy = ::core::primitive::u64;
+            pub type SignedMaxSubmissionsTy = ::core::primitive::u32;
+            pub type SignedMaxWeightTy = ::core::primitive::u64;
+            pub type SignedRewardBaseTy = ::core::primitive::u128;
+            pub type SignedDepositBaseTy = ::core::primitive::u128;
+            pub type SignedDepositByteTy = ::core::primitive::u128;
+            pub type SignedDepositWeightTy = ::core::primitive::u128;
+            pub type MaxElectingVotersTy = ::core::primitive::u32;
+            pub type MaxElectableTargetsTy = ::core::primitive::u16;
+            pub type MinerMaxLengthTy = ::core::primitive::u32;
+            pub struct ConstantsApi<'a, T> {
+                metadata: &'a ::subxt::Metadata,
+                marker: ::core::marker::PhantomData<T>,
             }
             impl<'a, T: ::subxt::Config> ConstantsApi<'a, T> {
                 pub fn new(client: &'a ::subxt::Client<T>) -> Self {
-                    Self { client }
+                    Self::from_metadata(client.metadata())
+                }
+            }
+            impl<'a, T> ConstantsApi<'a, T> {
+                #[doc = r" Read constants straight from `metadata`, without a client to fetch it with."]
+                #[doc = r""]
+                #[doc = r" `T` is only needed by [`Self::new`], so any type will do here."]
+                pub fn from_metadata(metadata: &'a ::subxt::Metadata) -> Self {
+                    Self {
+                        metadata,
+                        marker: ::core::marker::PhantomData,
+                    }
                 }
+                #[doc = " Duration of the unsigned phase."]
                 pub fn unsigned_phase(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u32, ::subxt::BasicError>
+                ) -> ::core::result::Result<UnsignedPhaseTy, ::subxt::BasicError>
                 {
-                    let pallet = self
-                        .client
-                        .metadata()
-                        .pallet("ElectionProviderMultiPhase")?;
-                    let constant = pallet.constant("UnsignedPhase")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("ElectionProviderMultiPhase")?;
+                    let value =
+                        pallet.decode_constant::<UnsignedPhaseTy>("UnsignedPhase")?;
                     Ok(value)
                 }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn unsigned_phase_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("ElectionProviderMultiPhase")?;
+                    let value = pallet.raw_constant("UnsignedPhase")?;
+                    Ok(value)
+                }
+                #[doc = " Duration of the signed phase."]
                 pub fn signed_phase(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u32, ::subxt::BasicError>
+                ) -> ::core::result::Result<SignedPhaseTy, ::subxt::BasicError>
                 {
-                    let pallet = self
-                        .client
-                        .metadata()
-                        .pallet("ElectionProviderMultiPhase")?;
-                    let constant = pallet.constant("SignedPhase")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("ElectionProviderMultiPhase")?;
+                    let value = pallet.decode_constant::<SignedPhaseTy>("SignedPhase")?;
                     Ok(value)
                 }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn signed_phase_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("ElectionProviderMultiPhase")?;
+                    let value = pallet.raw_constant("SignedPhase")?;
+                    Ok(value)
+                }
+                #[doc = " The minimum amount of improvement to the solution score that defines a solution as"]
+                #[doc = " \"better\" (in any phase)."]
                 pub fn solution_improvement_threshold(
                     &self,
                 ) -> ::core::result::Result<
-                    runtime_types::sp_arithmetic::per_things::Perbill,
+                    SolutionImprovementThresholdTy,
                     ::subxt::BasicError,
                 > {
-                    let pallet = self
-                        .client
-                        .metadata()
-                        .pallet("ElectionProviderMultiPhase")?;
-                    let constant = pallet.constant("SolutionImprovementThreshold")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("ElectionProviderMultiPhase")?;
+                    let value = pallet
+                        .decode_constant::<SolutionImprovementThresholdTy>(
+                            "SolutionImprovementThreshold",
+                        )?;
                     Ok(value)
                 }
-                pub fn offchain_repeat(
+                pub fn solution_improvement_threshold_inner(
                     &self,
                 ) -> ::core::result::Result<::core::primitive::u32, ::subxt::BasicError>
                 {
-                    let pallet = self
-                        .client
-                        .metadata()
-                        .pallet("ElectionProviderMultiPhase")?;
-                    let constant = pallet.constant("OffchainRepeat")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("ElectionProviderMultiPhase")?;
+                    let value = pallet
+                        .decode_constant::<SolutionImprovementThresholdTy>(
+                            "SolutionImprovementThreshold",
+                        )?;
+                    Ok(value.0)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn solution_improvement_threshold_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("ElectionProviderMultiPhase")?;
+                    let value = pallet.raw_constant("SolutionImprovementThreshold")?;
+                    Ok(value)
+                }
+                #[doc = " The repeat threshold of the offchain worker."]
+                #[doc = ""]
+                #[doc = " For example, if it is 5, that means that at least 5 blocks will elapse between attempts"]
+                #[doc = " to submit the worker's solution."]
+                pub fn offchain_repeat(
+                    &self,
+                ) -> ::core::result::Result<OffchainRepeatTy, ::subxt::BasicError>
+                {
+                    let pallet = self.metadata.pallet("ElectionProviderMultiPhase")?;
+                    let value =
+                        pallet.decode_constant::<OffchainRepeatTy>("OffchainRepeat")?;
+                    Ok(value)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn offchain_repeat_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("ElectionProviderMultiPhase")?;
+                    let value = pallet.raw_constant("OffchainRepeat")?;
                     Ok(value)
                 }
+                #[doc = " The priority of the unsigned transaction submitted in the unsigned-phase"]
                 pub fn miner_tx_priority(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u64, ::subxt::BasicError>
+                ) -> ::core::result::Result<MinerTxPriorityTy, ::subxt::BasicError>
                 {
-                    let pallet = self
-                        .client
-                        .metadata()
-                        .pallet("ElectionProviderMultiPhase")?;
-                    let constant = pallet.constant("MinerTxPriority")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("ElectionProviderMultiPhase")?;
+                    let value =
+                        pallet.decode_constant::<MinerTxPriorityTy>("MinerTxPriority")?;
+                    Ok(value)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn miner_tx_priority_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("ElectionProviderMultiPhase")?;
+                    let value = pallet.raw_constant("MinerTxPriority")?;
                     Ok(value)
                 }
+                #[doc = " Maximum weight that the miner should consume."]
+                #[doc = ""]
+                #[doc = " The miner will ensure that the total weight of the unsigned solution will not exceed"]
+                #[doc = " this value, based on [`WeightInfo::submit_unsigned`]."]
                 pub fn miner_max_weight(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u64, ::subxt::BasicError>
+                ) -> ::core::result::Result<MinerMaxWeightTy, ::subxt::BasicError>
                 {
-                    let pallet = self
-                        .client
-                        .metadata()
-                        .pallet("ElectionProviderMultiPhase")?;
-                    let constant = pallet.constant("MinerMaxWeight")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("ElectionProviderMultiPhase")?;
+                    let value =
+                        pallet.decode_constant::<MinerMaxWeightTy>("MinerMaxWeight")?;
                     Ok(value)
                 }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn miner_max_weight_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("ElectionProviderMultiPhase")?;
+                    let value = pallet.raw_constant("MinerMaxWeight")?;
+                    Ok(value)
+                }
+                #[doc = " Maximum number of signed submissions that can be queued."]
+                #[doc = ""]
+                #[doc = " It is best to avoid adjusting this during an election, as it impacts downstream data"]
+                #[doc = " structures. In particular, `SignedSubmissionIndices<T>` is bounded on this value. If you"]
+                #[doc = " update this value during an election, you _must_ ensure that"]
+                #[doc = " `SignedSubmissionIndices.len()` is less than or equal to the new value. Otherwise,"]
+                #[doc = " attempts to submit new solutions may cause a runtime panic."]
                 pub fn signed_max_submissions(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u32, ::subxt::BasicError>
+                ) -> ::core::result::Result<SignedMaxSubmissionsTy, ::subxt::BasicError>
                 {
-                    let pallet = self
-                        .client
-                        .metadata()
-                        .pallet("ElectionProviderMultiPhase")?;
-                    let constant = pallet.constant("SignedMaxSubmissions")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("ElectionProviderMultiPhase")?;
+                    let value = pallet.decode_constant::<SignedMaxSubmissionsTy>(
+                        "SignedMaxSubmissions",
+                    )?;
                     Ok(value)
                 }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn signed_max_submissions_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("ElectionProviderMultiPhase")?;
+                    let value = pallet.raw_constant("SignedMaxSubmissions")?;
+                    Ok(value)
+                }
+                #[doc = " Maximum weight of a signed solution."]
+                #[doc = ""]
+                #[doc = " This should probably be similar to [`Config::MinerMaxWeight`]."]
                 pub fn signed_max_weight(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u64, ::subxt::BasicError>
+                ) -> ::core::result::Result<SignedMaxWeightTy, ::subxt::BasicError>
                 {
-                    let pallet = self
-                        .client
-                        .metadata()
-                        .pallet("ElectionProviderMultiPhase")?;
-                    let constant = pallet.constant("SignedMaxWeight")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("ElectionProviderMultiPhase")?;
+                    let value =
+                        pallet.decode_constant::<SignedMaxWeightTy>("SignedMaxWeight")?;
                     Ok(value)
                 }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn signed_max_weight_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("ElectionProviderMultiPhase")?;
+                    let value = pallet.raw_constant("SignedMaxWeight")?;
+                    Ok(value)
+                }
+                #[doc = " Base reward for a signed solution"]
                 pub fn signed_reward_base(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u128, ::subxt::BasicError>
+                ) -> ::core::result::Result<SignedRewardBaseTy, ::subxt::BasicError>
                 {
-                    let pallet = self
-                        .client
-                        .metadata()
-                        .pallet("ElectionProviderMultiPhase")?;
-                    let constant = pallet.constant("SignedRewardBase")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("ElectionProviderMultiPhase")?;
+                    let value = pallet
+                        .decode_constant::<SignedRewardBaseTy>("SignedRewardBase")?;
+                    Ok(value)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn signed_reward_base_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("ElectionProviderMultiPhase")?;
+                    let value = pallet.raw_constant("SignedRewardBase")?;
                     Ok(value)
                 }
+                #[doc = " Base deposit for a signed solution."]
                 pub fn signed_deposit_base(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u128, ::subxt::BasicError>
+                ) -> ::core::result::Result<SignedDepositBaseTy, ::subxt::BasicError>
                 {
-                    let pallet = self
-                        .client
-                        .metadata()
-                        .pallet("ElectionProviderMultiPhase")?;
-                    let constant = pallet.constant("SignedDepositBase")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("ElectionProviderMultiPhase")?;
+                    let value = pallet
+                        .decode_constant::<SignedDepositBaseTy>("SignedDepositBase")?;
                     Ok(value)
                 }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn signed_deposit_base_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("ElectionProviderMultiPhase")?;
+                    let value = pallet.raw_constant("SignedDepositBase")?;
+                    Ok(value)
+                }
+                #[doc = " Per-byte deposit for a signed solution."]
                 pub fn signed_deposit_byte(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u128, ::subxt::BasicError>
+                ) -> ::core::result::Result<SignedDepositByteTy, ::subxt::BasicError>
                 {
-                    let pallet = self
-                        .client
-                        .metadata()
-                        .pallet("ElectionProviderMultiPhase")?;
-                    let constant = pallet.constant("SignedDepositByte")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("ElectionProviderMultiPhase")?;
+                    let value = pallet
+                        .decode_constant::<SignedDepositByteTy>("SignedDepositByte")?;
+                    Ok(value)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn signed_deposit_byte_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("ElectionProviderMultiPhase")?;
+                    let value = pallet.raw_constant("SignedDepositByte")?;
                     Ok(value)
                 }
+                #[doc = " Per-weight deposit for a signed solution."]
                 pub fn signed_deposit_weight(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u128, ::subxt::BasicError>
+                ) -> ::core::result::Result<SignedDepositWeightTy, ::subxt::BasicError>
                 {
-                    let pallet = self
-                        .client
-                        .metadata()
-                        .pallet("ElectionProviderMultiPhase")?;
-                    let constant = pallet.constant("SignedDepositWeight")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("ElectionProviderMultiPhase")?;
+                    let value = pallet.decode_constant::<SignedDepositWeightTy>(
+                        "SignedDepositWeight",
+                    )?;
                     Ok(value)
                 }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn signed_deposit_weight_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("ElectionProviderMultiPhase")?;
+                    let value = pallet.raw_constant("SignedDepositWeight")?;
+                    Ok(value)
+                }
+                #[doc = " The maximum number of electing voters to put in the snapshot. At the moment, snapshots"]
+                #[doc = " are only over a single block, but once multi-block elections are introduced they will"]
+                #[doc = " take place over multiple blocks."]
                 pub fn max_electing_voters(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u32, ::subxt::BasicError>
+                ) -> ::core::result::Result<MaxElectingVotersTy, ::subxt::BasicError>
                 {
-                    let pallet = self
-                        .client
-                        .metadata()
-                        .pallet("ElectionProviderMultiPhase")?;
-                    let constant = pallet.constant("MaxElectingVoters")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("ElectionProviderMultiPhase")?;
+                    let value = pallet
+                        .decode_constant::<MaxElectingVotersTy>("MaxElectingVoters")?;
+                    Ok(value)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn max_electing_voters_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("ElectionProviderMultiPhase")?;
+                    let value = pallet.raw_constant("MaxElectingVoters")?;
+                    Ok(value)
+                }
+                #[doc = " The maximum number of electable targets to put in the snapshot."]
+                pub fn max_electable_targets(
+                    &self,
+                ) -> ::core::result::Result<MaxElectableTargetsTy, ::subxt::BasicError>
+                {
+                    let pallet = self.metadata.pallet("ElectionProviderMultiPhase")?;
+                    let value = pallet.decode_constant::<MaxElectableTargetsTy>(
+                        "MaxElectableTargets",
+                    )?;
+                    Ok(value)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn max_electable_targets_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("ElectionProviderMultiPhase")?;
+                    let value = pallet.raw_constant("MaxElectableTargets")?;
                     Ok(value)
                 }
-                pub fn max_electable_targets(
+                #[doc = " Maximum length (bytes) that the mined solution should consume."]
+                #[doc = ""]
+                #[doc = " The miner will ensure that the total length of the unsigned solution will not exceed"]
+                #[doc = " this value."]
+                pub fn miner_max_length(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u16, ::subxt::BasicError>
+                ) -> ::core::result::Result<MinerMaxLengthTy, ::subxt::BasicError>
                 {
-                    let pallet = self
-                        .client
-                        .metadata()
-                        .pallet("ElectionProviderMultiPhase")?;
-                    let constant = pallet.constant("MaxElectableTargets")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("ElectionProviderMultiPhase")?;
+                    let value =
+                        pallet.decode_constant::<MinerMaxLengthTy>("MinerMaxLength")?;
                     Ok(value)
                 }
-                pub fn miner_max_length(
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn miner_max_length_raw(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u32, ::subxt::BasicError>
-                {
-                    let pallet = self
-                        .client
-                        .metadata()
-                        .pallet("ElectionProviderMultiPhase")?;
-                    let constant = pallet.constant("MinerMaxLength")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("ElectionProviderMultiPhase")?;
+                    let value = pallet.raw_constant("MinerMaxLength")?;
                     Ok(value)
                 }
+                #[doc = r" Returns the name and raw SCALE encoded value of every constant in this pallet."]
+                pub fn all(
+                    &self,
+                ) -> ::core::result::Result<
+                    ::std::vec::Vec<(
+                        &'static str,
+                        ::std::vec::Vec<::core::primitive::u8>,
+                    )>,
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("ElectionProviderMultiPhase")?;
+                    let mut constants = ::std::vec::Vec::new();
+                    constants.push((
+                        "UnsignedPhase",
+                        pallet.constant("UnsignedPhase")?.value.clone(),
+                    ));
+                    constants.push((
+                        "SignedPhase",
+                        pallet.constant("SignedPhase")?.value.clone(),
+                    ));
+                    constants.push((
+                        "SolutionImprovementThreshold",
+                        pallet
+                            .constant("SolutionImprovementThreshold")?
+                            .value
+                            .clone(),
+                    ));
+                    constants.push((
+                        "OffchainRepeat",
+                        pallet.constant("OffchainRepeat")?.value.clone(),
+                    ));
+                    constants.push((
+                        "MinerTxPriority",
+                        pallet.constant("MinerTxPriority")?.value.clone(),
+                    ));
+                    constants.push((
+                        "MinerMaxWeight",
+                        pallet.constant("MinerMaxWeight")?.value.clone(),
+                    ));
+                    constants.push((
+                        "SignedMaxSubmissions",
+                        pallet.constant("SignedMaxSubmissions")?.value.clone(),
+                    ));
+                    constants.push((
+                        "SignedMaxWeight",
+                        pallet.constant("SignedMaxWeight")?.value.clone(),
+                    ));
+                    constants.push((
+                        "SignedRewardBase",
+                        pallet.constant("SignedRewardBase")?.value.clone(),
+                    ));
+                    constants.push((
+                        "SignedDepositBase",
+                        pallet.constant("SignedDepositBase")?.value.clone(),
+                    ));
+                    constants.push((
+                        "SignedDepositByte",
+                        pallet.constant("SignedDepositByte")?.value.clone(),
+                    ));
+                    constants.push((
+                        "SignedDepositWeight",
+                        pallet.constant("SignedDepositWeight")?.value.clone(),
+                    ));
+                    constants.push((
+                        "MaxElectingVoters",
+                        pallet.constant("MaxElectingVoters")?.value.clone(),
+                    ));
+                    constants.push((
+                        "MaxElectableTargets",
+                        pallet.constant("MaxElectableTargets")?.value.clone(),
+                    ));
+                    constants.push((
+                        "MinerMaxLength",
+                        pallet.constant("MinerMaxLength")?.value.clone(),
+                    ));
+                    Ok(constants)
+                }
             }
         }
     }
@@ -14077,24 +16855,110 @@ pub mod api {
         }
         pub mod constants {
             use super::runtime_types;
-            pub struct ConstantsApi<'a, T: ::subxt::Config> {
-                client: &'a ::subxt::Client<T>,
+            pub type BagThresholdsTy = ::std::vec::Vec<::core::primitive::u64>;
+            pub struct ConstantsApi<'a, T> {
+                metadata: &'a ::subxt::Metadata,
+                marker: ::core::marker::PhantomData<T>,
             }
             impl<'a, T: ::subxt::Config> ConstantsApi<'a, T> {
                 pub fn new(client: &'a ::subxt::Client<T>) -> Self {
-                    Self { client }
+                    Self::from_metadata(client.metadata())
+                }
+            }
+            impl<'a, T> ConstantsApi<'a, T> {
+                #[doc = r" Read constants straight from `metadata`, without a client to fetch it with."]
+                #[doc = r""]
+                #[doc = r" `T` is only needed by [`Self::new`], so any type will do here."]
+                pub fn from_metadata(metadata: &'a ::subxt::Metadata) -> Self {
+                    Self {
+                        metadata,
+                        marker: ::core::marker::PhantomData,
+                    }
                 }
+                #[doc = " The list of thresholds separating the various bags."]
+                #[doc = ""]
+                #[doc = " Ids are separated into unsorted bags according to their score. This specifies the"]
+                #[doc = " thresholds separating the bags. An id's bag is the largest bag for which the id's score"]
+                #[doc = " is less than or equal to its upper threshold."]
+                #[doc = ""]
+                #[doc = " When ids are iterated, higher bags are iterated completely before lower bags. This means"]
+                #[doc = " that iteration is _semi-sorted_: ids of higher score tend to come before ids of lower"]
+                #[doc = " score, but peer ids within a particular bag are sorted in insertion order."]
+                #[doc = ""]
+                #[doc = " # Expressing the constant"]
+                #[doc = ""]
+                #[doc = " This constant must be sorted in strictly increasing order. Duplicate items are not"]
+                #[doc = " permitted."]
+                #[doc = ""]
+                #[doc = " There is an implied upper limit of `Score::MAX`; that value does not need to be"]
+                #[doc = " specified within the bag. For any two threshold lists, if one ends with"]
+                #[doc = " `Score::MAX`, the other one does not, and they are otherwise equal, the two"]
+                #[doc = " lists will behave identically."]
+                #[doc = ""]
+                #[doc = " # Calculation"]
+                #[doc = ""]
+                #[doc = " It is recommended to generate the set of thresholds in a geometric series, such that"]
+                #[doc = " there exists some constant ratio such that `threshold[k + 1] == (threshold[k] *"]
+                #[doc = " constant_ratio).max(threshold[k] + 1)` for all `k`."]
+                #[doc = ""]
+                #[doc = " The helpers in the `/utils/frame/generate-bags` module can simplify this calculation."]
+                #[doc = ""]
+                #[doc = " # Examples"]
+                #[doc = ""]
+                #[doc = " - If `BagThresholds::get().is_empty()`, then all ids are put into the same bag, and"]
+                #[doc = "   iteration is strictly in insertion order."]
+                #[doc = " - If `BagThresholds::get().len() == 64`, and the thresholds are determined according to"]
+                #[doc = "   the procedure given above, then the constant ratio is equal to 2."]
+                #[doc = " - If `BagThresholds::get().len() == 200`, and the thresholds are determined according to"]
+                #[doc = "   the procedure given above, then the constant ratio is approximately equal to 1.248."]
+                #[doc = " - If the threshold list begins `[1, 2, 3, ...]`, then an id with score 0 or 1 will fall"]
+                #[doc = "   into bag 0, an id with score 2 will fall into bag 1, etc."]
+                #[doc = ""]
+                #[doc = " # Migration"]
+                #[doc = ""]
+                #[doc = " In the event that this list ever changes, a copy of the old bags list must be retained."]
+                #[doc = " With that `List::migrate` can be called, which will perform the appropriate migration."]
                 pub fn bag_thresholds(
                     &self,
+                ) -> ::core::result::Result<BagThresholdsTy, ::subxt::BasicError>
+                {
+                    let pallet = self.metadata.pallet("BagsList")?;
+                    let value =
+                        pallet.decode_constant::<BagThresholdsTy>("BagThresholds")?;
+                    Ok(value)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn bag_thresholds_raw(
+                    &self,
                 ) -> ::core::result::Result<
-                    ::std::vec::Vec<::core::primitive::u64>,
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
                     ::subxt::BasicError,
                 > {
-                    let pallet = self.client.metadata().pallet("BagsList")?;
-                    let constant = pallet.constant("BagThresholds")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("BagsList")?;
+                    let value = pallet.raw_constant("BagThresholds")?;
                     Ok(value)
                 }
+                #[doc = r" Returns the name and raw SCALE encoded value of every constant in this pallet."]
+                pub fn all(
+                    &self,
+                ) -> ::core::result::Result<
+                    ::std::vec::Vec<(
+                        &'static str,
+                        ::std::vec::Vec<::core::primitive::u8>,
+                    )>,
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("BagsList")?;
+                    let mut constants = ::std::vec::Vec::new();
+                    constants.push((
+                        "BagThresholds",
+                        pallet.constant("BagThresholds")?.value.clone(),
+                    ));
+                    Ok(constants)
+                }
             }
         }
     }
@@ -16825,22 +19689,68 @@ pub mod api {
         }
         pub mod constants {
             use super::runtime_types;
-            pub struct ConstantsApi<'a, T: ::subxt::Config> {
-                client: &'a ::subxt::Client<T>,
+            pub type UnsignedPriorityTy = ::core::primitive::u64;
+            pub struct ConstantsApi<'a, T> {
+                metadata: &'a ::subxt::Metadata,
+                marker: ::core::marker::PhantomData<T>,
             }
             impl<'a, T: ::subxt::Config> ConstantsApi<'a, T> {
                 pub fn new(client: &'a ::subxt::Client<T>) -> Self {
-                    Self { client }
+                    Self::from_metadata(client.metadata())
+                }
+            }
+            impl<'a, T> ConstantsApi<'a, T> {
+                #[doc = r" Read constants straight from `metadata`, without a client to fetch it with."]
+                #[doc = r""]
+                #[doc = r" `T` is only needed by [`Self::new`], so any type will do here."]
+                pub fn from_metadata(metadata: &'a ::subxt::Metadata) -> Self {
+                    Self {
+                        metadata,
+                        marker: ::core::marker::PhantomData,
+                    }
                 }
+                #[doc = " The `Paras::UnsignedPriority` constant, of type `::core::primitive::u64`."]
                 pub fn unsigned_priority(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u64, ::subxt::BasicError>
+                ) -> ::core::result::Result<UnsignedPriorityTy, ::subxt::BasicError>
                 {
-                    let pallet = self.client.metadata().pallet("Paras")?;
-                    let constant = pallet.constant("UnsignedPriority")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("Paras")?;
+                    let value = pallet
+                        .decode_constant::<UnsignedPriorityTy>("UnsignedPriority")?;
+                    Ok(value)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn unsigned_priority_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Paras")?;
+                    let value = pallet.raw_constant("UnsignedPriority")?;
                     Ok(value)
                 }
+                #[doc = r" Returns the name and raw SCALE encoded value of every constant in this pallet."]
+                pub fn all(
+                    &self,
+                ) -> ::core::result::Result<
+                    ::std::vec::Vec<(
+                        &'static str,
+                        ::std::vec::Vec<::core::primitive::u8>,
+                    )>,
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Paras")?;
+                    let mut constants = ::std::vec::Vec::new();
+                    constants.push((
+                        "UnsignedPriority",
+                        pallet.constant("UnsignedPriority")?.value.clone(),
+                    ));
+                    Ok(constants)
+                }
             }
         }
     }
@@ -18663,31 +21573,97 @@ pub mod api {
         }
         pub mod constants {
             use super::runtime_types;
-            pub struct ConstantsApi<'a, T: ::subxt::Config> {
-                client: &'a ::subxt::Client<T>,
+            pub type ParaDepositTy = ::core::primitive::u128;
+            pub type DataDepositPerByteTy = ::core::primitive::u128;
+            pub struct ConstantsApi<'a, T> {
+                metadata: &'a ::subxt::Metadata,
+                marker: ::core::marker::PhantomData<T>,
             }
             impl<'a, T: ::subxt::Config> ConstantsApi<'a, T> {
                 pub fn new(client: &'a ::subxt::Client<T>) -> Self {
-                    Self { client }
+                    Self::from_metadata(client.metadata())
+                }
+            }
+            impl<'a, T> ConstantsApi<'a, T> {
+                #[doc = r" Read constants straight from `metadata`, without a client to fetch it with."]
+                #[doc = r""]
+                #[doc = r" `T` is only needed by [`Self::new`], so any type will do here."]
+                pub fn from_metadata(metadata: &'a ::subxt::Metadata) -> Self {
+                    Self {
+                        metadata,
+                        marker: ::core::marker::PhantomData,
+                    }
                 }
+                #[doc = " The deposit to be paid to run a parathread."]
+                #[doc = " This should include the cost for storing the genesis head and validation code."]
                 pub fn para_deposit(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u128, ::subxt::BasicError>
+                ) -> ::core::result::Result<ParaDepositTy, ::subxt::BasicError>
                 {
-                    let pallet = self.client.metadata().pallet("Registrar")?;
-                    let constant = pallet.constant("ParaDeposit")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("Registrar")?;
+                    let value = pallet.decode_constant::<ParaDepositTy>("ParaDeposit")?;
+                    Ok(value)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn para_deposit_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Registrar")?;
+                    let value = pallet.raw_constant("ParaDeposit")?;
                     Ok(value)
                 }
+                #[doc = " The deposit to be paid per byte stored on chain."]
                 pub fn data_deposit_per_byte(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u128, ::subxt::BasicError>
+                ) -> ::core::result::Result<DataDepositPerByteTy, ::subxt::BasicError>
                 {
-                    let pallet = self.client.metadata().pallet("Registrar")?;
-                    let constant = pallet.constant("DataDepositPerByte")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("Registrar")?;
+                    let value = pallet
+                        .decode_constant::<DataDepositPerByteTy>("DataDepositPerByte")?;
+                    Ok(value)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn data_deposit_per_byte_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Registrar")?;
+                    let value = pallet.raw_constant("DataDepositPerByte")?;
                     Ok(value)
                 }
+                #[doc = r" Returns the name and raw SCALE encoded value of every constant in this pallet."]
+                pub fn all(
+                    &self,
+                ) -> ::core::result::Result<
+                    ::std::vec::Vec<(
+                        &'static str,
+                        ::std::vec::Vec<::core::primitive::u8>,
+                    )>,
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Registrar")?;
+                    let mut constants = ::std::vec::Vec::new();
+                    constants.push((
+                        "ParaDeposit",
+                        pallet.constant("ParaDeposit")?.value.clone(),
+                    ));
+                    constants.push((
+                        "DataDepositPerByte",
+                        pallet.constant("DataDepositPerByte")?.value.clone(),
+                    ));
+                    Ok(constants)
+                }
             }
         }
     }
@@ -18880,31 +21856,95 @@ pub mod api {
         }
         pub mod constants {
             use super::runtime_types;
-            pub struct ConstantsApi<'a, T: ::subxt::Config> {
-                client: &'a ::subxt::Client<T>,
+            pub type LeasePeriodTy = ::core::primitive::u32;
+            pub type LeaseOffsetTy = ::core::primitive::u32;
+            pub struct ConstantsApi<'a, T> {
+                metadata: &'a ::subxt::Metadata,
+                marker: ::core::marker::PhantomData<T>,
             }
             impl<'a, T: ::subxt::Config> ConstantsApi<'a, T> {
                 pub fn new(client: &'a ::subxt::Client<T>) -> Self {
-                    Self { client }
+                    Self::from_metadata(client.metadata())
+                }
+            }
+            impl<'a, T> ConstantsApi<'a, T> {
+                #[doc = r" Read constants straight from `metadata`, without a client to fetch it with."]
+                #[doc = r""]
+                #[doc = r" `T` is only needed by [`Self::new`], so any type will do here."]
+                pub fn from_metadata(metadata: &'a ::subxt::Metadata) -> Self {
+                    Self {
+                        metadata,
+                        marker: ::core::marker::PhantomData,
+                    }
                 }
+                #[doc = " The number of blocks over which a single period lasts."]
                 pub fn lease_period(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u32, ::subxt::BasicError>
+                ) -> ::core::result::Result<LeasePeriodTy, ::subxt::BasicError>
                 {
-                    let pallet = self.client.metadata().pallet("Slots")?;
-                    let constant = pallet.constant("LeasePeriod")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("Slots")?;
+                    let value = pallet.decode_constant::<LeasePeriodTy>("LeasePeriod")?;
+                    Ok(value)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn lease_period_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Slots")?;
+                    let value = pallet.raw_constant("LeasePeriod")?;
                     Ok(value)
                 }
+                #[doc = " The number of blocks to offset each lease period by."]
                 pub fn lease_offset(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u32, ::subxt::BasicError>
+                ) -> ::core::result::Result<LeaseOffsetTy, ::subxt::BasicError>
                 {
-                    let pallet = self.client.metadata().pallet("Slots")?;
-                    let constant = pallet.constant("LeaseOffset")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("Slots")?;
+                    let value = pallet.decode_constant::<LeaseOffsetTy>("LeaseOffset")?;
+                    Ok(value)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn lease_offset_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Slots")?;
+                    let value = pallet.raw_constant("LeaseOffset")?;
                     Ok(value)
                 }
+                #[doc = r" Returns the name and raw SCALE encoded value of every constant in this pallet."]
+                pub fn all(
+                    &self,
+                ) -> ::core::result::Result<
+                    ::std::vec::Vec<(
+                        &'static str,
+                        ::std::vec::Vec<::core::primitive::u8>,
+                    )>,
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Slots")?;
+                    let mut constants = ::std::vec::Vec::new();
+                    constants.push((
+                        "LeasePeriod",
+                        pallet.constant("LeasePeriod")?.value.clone(),
+                    ));
+                    constants.push((
+                        "LeaseOffset",
+                        pallet.constant("LeaseOffset")?.value.clone(),
+                    ));
+                    Ok(constants)
+                }
             }
         }
     }
@@ -19226,49 +22266,158 @@ pub mod api {
         }
         pub mod constants {
             use super::runtime_types;
-            pub struct ConstantsApi<'a, T: ::subxt::Config> {
-                client: &'a ::subxt::Client<T>,
+            pub type EndingPeriodTy = ::core::primitive::u32;
+            pub type SampleLengthTy = ::core::primitive::u32;
+            pub type SlotRangeCountTy = ::core::primitive::u32;
+            pub type LeasePeriodsPerSlotTy = ::core::primitive::u32;
+            pub struct ConstantsApi<'a, T> {
+                metadata: &'a ::subxt::Metadata,
+                marker: ::core::marker::PhantomData<T>,
             }
             impl<'a, T: ::subxt::Config> ConstantsApi<'a, T> {
                 pub fn new(client: &'a ::subxt::Client<T>) -> Self {
-                    Self { client }
+                    Self::from_metadata(client.metadata())
+                }
+            }
+            impl<'a, T> ConstantsApi<'a, T> {
+                #[doc = r" Read constants straight from `metadata`, without a client to fetch it with."]
+                #[doc = r""]
+                #[doc = r" `T` is only needed by [`Self::new`], so any type will do here."]
+                pub fn from_metadata(metadata: &'a ::subxt::Metadata) -> Self {
+                    Self {
+                        metadata,
+                        marker: ::core::marker::PhantomData,
+                    }
                 }
+                #[doc = " The number of blocks over which an auction may be retroactively ended."]
                 pub fn ending_period(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u32, ::subxt::BasicError>
+                ) -> ::core::result::Result<EndingPeriodTy, ::subxt::BasicError>
                 {
-                    let pallet = self.client.metadata().pallet("Auctions")?;
-                    let constant = pallet.constant("EndingPeriod")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("Auctions")?;
+                    let value =
+                        pallet.decode_constant::<EndingPeriodTy>("EndingPeriod")?;
+                    Ok(value)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn ending_period_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Auctions")?;
+                    let value = pallet.raw_constant("EndingPeriod")?;
                     Ok(value)
                 }
+                #[doc = " The length of each sample to take during the ending period."]
+                #[doc = ""]
+                #[doc = " `EndingPeriod` / `SampleLength` = Total # of Samples"]
                 pub fn sample_length(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u32, ::subxt::BasicError>
+                ) -> ::core::result::Result<SampleLengthTy, ::subxt::BasicError>
                 {
-                    let pallet = self.client.metadata().pallet("Auctions")?;
-                    let constant = pallet.constant("SampleLength")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("Auctions")?;
+                    let value =
+                        pallet.decode_constant::<SampleLengthTy>("SampleLength")?;
+                    Ok(value)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn sample_length_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Auctions")?;
+                    let value = pallet.raw_constant("SampleLength")?;
                     Ok(value)
                 }
+                #[doc = " The `Auctions::SlotRangeCount` constant, of type `::core::primitive::u32`."]
                 pub fn slot_range_count(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u32, ::subxt::BasicError>
+                ) -> ::core::result::Result<SlotRangeCountTy, ::subxt::BasicError>
                 {
-                    let pallet = self.client.metadata().pallet("Auctions")?;
-                    let constant = pallet.constant("SlotRangeCount")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("Auctions")?;
+                    let value =
+                        pallet.decode_constant::<SlotRangeCountTy>("SlotRangeCount")?;
+                    Ok(value)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn slot_range_count_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Auctions")?;
+                    let value = pallet.raw_constant("SlotRangeCount")?;
                     Ok(value)
                 }
+                #[doc = " The `Auctions::LeasePeriodsPerSlot` constant, of type `::core::primitive::u32`."]
                 pub fn lease_periods_per_slot(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u32, ::subxt::BasicError>
+                ) -> ::core::result::Result<LeasePeriodsPerSlotTy, ::subxt::BasicError>
                 {
-                    let pallet = self.client.metadata().pallet("Auctions")?;
-                    let constant = pallet.constant("LeasePeriodsPerSlot")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("Auctions")?;
+                    let value = pallet.decode_constant::<LeasePeriodsPerSlotTy>(
+                        "LeasePeriodsPerSlot",
+                    )?;
+                    Ok(value)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn lease_periods_per_slot_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Auctions")?;
+                    let value = pallet.raw_constant("LeasePeriodsPerSlot")?;
                     Ok(value)
                 }
+                #[doc = r" Returns the name and raw SCALE encoded value of every constant in this pallet."]
+                pub fn all(
+                    &self,
+                ) -> ::core::result::Result<
+                    ::std::vec::Vec<(
+                        &'static str,
+                        ::std::vec::Vec<::core::primitive::u8>,
+                    )>,
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Auctions")?;
+                    let mut constants = ::std::vec::Vec::new();
+                    constants.push((
+                        "EndingPeriod",
+                        pallet.constant("EndingPeriod")?.value.clone(),
+                    ));
+                    constants.push((
+                        "SampleLength",
+                        pallet.constant("SampleLength")?.value.clone(),
+                    ));
+                    constants.push((
+                        "SlotRangeCount",
+                        pallet.constant("SlotRangeCount")?.value.clone(),
+                    ));
+                    constants.push((
+                        "LeasePeriodsPerSlot",
+                        pallet.constant("LeasePeriodsPerSlot")?.value.clone(),
+                    ));
+                    Ok(constants)
+                }
             }
         }
     }
@@ -19766,42 +22915,124 @@ pub mod api {
         }
         pub mod constants {
             use super::runtime_types;
-            pub struct ConstantsApi<'a, T: ::subxt::Config> {
-                client: &'a ::subxt::Client<T>,
+            pub type PalletIdTy = runtime_types::frame_support::PalletId;
+            pub type MinContributionTy = ::core::primitive::u128;
+            pub type RemoveKeysLimitTy = ::core::primitive::u32;
+            pub struct ConstantsApi<'a, T> {
+                metadata: &'a ::subxt::Metadata,
+                marker: ::core::marker::PhantomData<T>,
             }
             impl<'a, T: ::subxt::Config> ConstantsApi<'a, T> {
                 pub fn new(client: &'a ::subxt::Client<T>) -> Self {
-                    Self { client }
+                    Self::from_metadata(client.metadata())
+                }
+            }
+            impl<'a, T> ConstantsApi<'a, T> {
+                #[doc = r" Read constants straight from `metadata`, without a client to fetch it with."]
+                #[doc = r""]
+                #[doc = r" `T` is only needed by [`Self::new`], so any type will do here."]
+                pub fn from_metadata(metadata: &'a ::subxt::Metadata) -> Self {
+                    Self {
+                        metadata,
+                        marker: ::core::marker::PhantomData,
+                    }
                 }
+                #[doc = " `PalletId` for the crowdloan pallet. An appropriate value could be `PalletId(*b\"py/cfund\")`"]
                 pub fn pallet_id(
                     &self,
+                ) -> ::core::result::Result<PalletIdTy, ::subxt::BasicError>
+                {
+                    let pallet = self.metadata.pallet("Crowdloan")?;
+                    let value = pallet.decode_constant::<PalletIdTy>("PalletId")?;
+                    Ok(value)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn pallet_id_raw(
+                    &self,
                 ) -> ::core::result::Result<
-                    runtime_types::frame_support::PalletId,
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
                     ::subxt::BasicError,
                 > {
-                    let pallet = self.client.metadata().pallet("Crowdloan")?;
-                    let constant = pallet.constant("PalletId")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("Crowdloan")?;
+                    let value = pallet.raw_constant("PalletId")?;
                     Ok(value)
                 }
+                #[doc = " The minimum amount that may be contributed into a crowdloan. Should almost certainly be at"]
+                #[doc = " least `ExistentialDeposit`."]
                 pub fn min_contribution(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u128, ::subxt::BasicError>
+                ) -> ::core::result::Result<MinContributionTy, ::subxt::BasicError>
                 {
-                    let pallet = self.client.metadata().pallet("Crowdloan")?;
-                    let constant = pallet.constant("MinContribution")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("Crowdloan")?;
+                    let value =
+                        pallet.decode_constant::<MinContributionTy>("MinContribution")?;
+                    Ok(value)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn min_contribution_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Crowdloan")?;
+                    let value = pallet.raw_constant("MinContribution")?;
                     Ok(value)
                 }
+                #[doc = " Max number of storage keys to remove per extrinsic call."]
                 pub fn remove_keys_limit(
                     &self,
-                ) -> ::core::result::Result<::core::primitive::u32, ::subxt::BasicError>
+                ) -> ::core::result::Result<RemoveKeysLimitTy, ::subxt::BasicError>
                 {
-                    let pallet = self.client.metadata().pallet("Crowdloan")?;
-                    let constant = pallet.constant("RemoveKeysLimit")?;
-                    let value = ::subxt::codec::Decode::decode(&mut &constant.value[..])?;
+                    let pallet = self.metadata.pallet("Crowdloan")?;
+                    let value =
+                        pallet.decode_constant::<RemoveKeysLimitTy>("RemoveKeysLimit")?;
+                    Ok(value)
+                }
+                #[doc = r" The raw SCALE encoded value, along with the id of its type in the metadata."]
+                pub fn remove_keys_limit_raw(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::vec::Vec<::core::primitive::u8>,
+                        ::core::primitive::u32,
+                    ),
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Crowdloan")?;
+                    let value = pallet.raw_constant("RemoveKeysLimit")?;
                     Ok(value)
                 }
+                #[doc = r" Returns the name and raw SCALE encoded value of every constant in this pallet."]
+                pub fn all(
+                    &self,
+                ) -> ::core::result::Result<
+                    ::std::vec::Vec<(
+                        &'static str,
+                        ::std::vec::Vec<::core::primitive::u8>,
+                    )>,
+                    ::subxt::BasicError,
+                > {
+                    let pallet = self.metadata.pallet("Crowdloan")?;
+                    let mut constants = ::std::vec::Vec::new();
+                    constants
+                        .push(("PalletId", pallet.constant("PalletId")?.value.clone()));
+                    constants.push((
+                        "MinContribution",
+                        pallet.constant("MinContribution")?.value.clone(),
+                    ));
+                    constants.push((
+                        "RemoveKeysLimit",
+                        pallet.constant("RemoveKeysLimit")?.value.clone(),
+                    ));
+                    Ok(constants)
+                }
             }
         }
     }
@@ -28403,9 +31634,7 @@ pub mod api {
         X: ::subxt::extrinsic::ExtrinsicParams<T>,
     {
         pub fn constants(&'a self) -> ConstantsApi<'a, T> {
-            ConstantsApi {
-                client: &self.client,
-            }
+            ConstantsApi::new(&self.client)
         }
         pub fn storage(&'a self) -> StorageApi<'a, T> {
             StorageApi {
@@ -28461,105 +31690,122 @@ pub mod api {
             ::subxt::events::subscribe_finalized::<T, Event>(self.client).await
         }
     }
-    pub struct ConstantsApi<'a, T: ::subxt::Config> {
-        client: &'a ::subxt::Client<T>,
+    pub struct ConstantsApi<'a, T> {
+        metadata: &'a ::subxt::Metadata,
+        marker: ::core::marker::PhantomData<T>,
     }
     impl<'a, T: ::subxt::Config> ConstantsApi<'a, T> {
+        pub fn new(client: &'a ::subxt::Client<T>) -> Self {
+            Self::from_metadata(client.metadata())
+        }
+    }
+    impl<'a, T> ConstantsApi<'a, T> {
+        #[doc = r" Read constants straight from `metadata`, without a client to fetch it with."]
+        #[doc = r""]
+        #[doc = r" `T` is only needed by [`Self::new`], so any type will do here."]
+        pub fn from_metadata(metadata: &'a ::subxt::Metadata) -> Self {
+            Self {
+                metadata,
+                marker: ::core::marker::PhantomData,
+            }
+        }
         pub fn system(&self) -> system::constants::ConstantsApi<'a, T> {
-            system::constants::ConstantsApi::new(self.client)
+            system::constants::ConstantsApi::from_metadata(self.metadata)
         }
         pub fn scheduler(&self) -> scheduler::constants::ConstantsApi<'a, T> {
-            scheduler::constants::ConstantsApi::new(self.client)
+            scheduler::constants::ConstantsApi::from_metadata(self.metadata)
         }
         pub fn babe(&self) -> babe::constants::ConstantsApi<'a, T> {
-            babe::constants::ConstantsApi::new(self.client)
+            babe::constants::ConstantsApi::from_metadata(self.metadata)
         }
         pub fn timestamp(&self) -> timestamp::constants::ConstantsApi<'a, T> {
-            timestamp::constants::ConstantsApi::new(self.client)
+            timestamp::constants::ConstantsApi::from_metadata(self.metadata)
         }
         pub fn indices(&self) -> indices::constants::ConstantsApi<'a, T> {
-            indices::constants::ConstantsApi::new(self.client)
+            indices::constants::ConstantsApi::from_metadata(self.metadata)
         }
         pub fn balances(&self) -> balances::constants::ConstantsApi<'a, T> {
-            balances::constants::ConstantsApi::new(self.client)
+            balances::constants::ConstantsApi::from_metadata(self.metadata)
         }
         pub fn transaction_payment(
             &self,
         ) -> transaction_payment::constants::ConstantsApi<'a, T> {
-            transaction_payment::constants::ConstantsApi::new(self.client)
+            transaction_payment::constants::ConstantsApi::from_metadata(self.metadata)
         }
         pub fn authorship(&self) -> authorship::constants::ConstantsApi<'a, T> {
-            authorship::constants::ConstantsApi::new(self.client)
+            authorship::constants::ConstantsApi::from_metadata(self.metadata)
         }
         pub fn staking(&self) -> staking::constants::ConstantsApi<'a, T> {
-            staking::constants::ConstantsApi::new(self.client)
+            staking::constants::ConstantsApi::from_metadata(self.metadata)
         }
         pub fn grandpa(&self) -> grandpa::constants::ConstantsApi<'a, T> {
-            grandpa::constants::ConstantsApi::new(self.client)
+            grandpa::constants::ConstantsApi::from_metadata(self.metadata)
         }
         pub fn im_online(&self) -> im_online::constants::ConstantsApi<'a, T> {
-            im_online::constants::ConstantsApi::new(self.client)
+            im_online::constants::ConstantsApi::from_metadata(self.metadata)
         }
         pub fn democracy(&self) -> democracy::constants::ConstantsApi<'a, T> {
-            democracy::constants::ConstantsApi::new(self.client)
+            democracy::constants::ConstantsApi::from_metadata(self.metadata)
         }
         pub fn phragmen_election(
             &self,
         ) -> phragmen_election::constants::ConstantsApi<'a, T> {
-            phragmen_election::constants::ConstantsApi::new(self.client)
+            phragmen_election::constants::ConstantsApi::from_metadata(self.metadata)
         }
         pub fn treasury(&self) -> treasury::constants::ConstantsApi<'a, T> {
-            treasury::constants::ConstantsApi::new(self.client)
+            treasury::constants::ConstantsApi::from_metadata(self.metadata)
         }
         pub fn claims(&self) -> claims::constants::ConstantsApi<'a, T> {
-            claims::constants::ConstantsApi::new(self.client)
+            claims::constants::ConstantsApi::from_metadata(self.metadata)
         }
         pub fn vesting(&self) -> vesting::constants::ConstantsApi<'a, T> {
-            vesting::constants::ConstantsApi::new(self.client)
+            vesting::constants::ConstantsApi::from_metadata(self.metadata)
         }
         pub fn utility(&self) -> utility::constants::ConstantsApi<'a, T> {
-            utility::constants::ConstantsApi::new(self.client)
+            utility::constants::ConstantsApi::from_metadata(self.metadata)
         }
         pub fn identity(&self) -> identity::constants::ConstantsApi<'a, T> {
-            identity::constants::ConstantsApi::new(self.client)
+            identity::constants::ConstantsApi::from_metadata(self.metadata)
         }
         pub fn proxy(&self) -> proxy::constants::ConstantsApi<'a, T> {
-            proxy::constants::ConstantsApi::new(self.client)
+            proxy::constants::ConstantsApi::from_metadata(self.metadata)
         }
         pub fn multisig(&self) -> multisig::constants::ConstantsApi<'a, T> {
-            multisig::constants::ConstantsApi::new(self.client)
+            multisig::constants::ConstantsApi::from_metadata(self.metadata)
         }
         pub fn bounties(&self) -> bounties::constants::ConstantsApi<'a, T> {
-            bounties::constants::ConstantsApi::new(self.client)
+            bounties::constants::ConstantsApi::from_metadata(self.metadata)
         }
         pub fn child_bounties(&self) -> child_bounties::constants::ConstantsApi<'a, T> {
-            child_bounties::constants::ConstantsApi::new(self.client)
+            child_bounties::constants::ConstantsApi::from_metadata(self.metadata)
         }
         pub fn tips(&self) -> tips::constants::ConstantsApi<'a, T> {
-            tips::constants::ConstantsApi::new(self.client)
+            tips::constants::ConstantsApi::from_metadata(self.metadata)
         }
         pub fn election_provider_multi_phase(
             &self,
         ) -> election_provider_multi_phase::constants::ConstantsApi<'a, T> {
-            election_provider_multi_phase::constants::ConstantsApi::new(self.client)
+            election_provider_multi_phase::constants::ConstantsApi::from_metadata(
+                self.metadata,
+            )
         }
         pub fn bags_list(&self) -> bags_list::constants::ConstantsApi<'a, T> {
-            bags_list::constants::ConstantsApi::new(self.client)
+            bags_list::constants::ConstantsApi::from_metadata(self.metadata)
         }
         pub fn paras(&self) -> paras::constants::ConstantsApi<'a, T> {
-            paras::constants::ConstantsApi::new(self.client)
+            paras::constants::ConstantsApi::from_metadata(self.metadata)
         }
         pub fn registrar(&self) -> registrar::constants::ConstantsApi<'a, T> {
-            registrar::constants::ConstantsApi::new(self.client)
+            registrar::constants::ConstantsApi::from_metadata(self.metadata)
         }
         pub fn slots(&self) -> slots::constants::ConstantsApi<'a, T> {
-            slots::constants::ConstantsApi::new(self.client)
+            slots::constants::ConstantsApi::from_metadata(self.metadata)
         }
         pub fn auctions(&self) -> auctions::constants::ConstantsApi<'a, T> {
-            auctions::constants::ConstantsApi::new(self.client)
+            auctions::constants::ConstantsApi::from_metadata(self.metadata)
         }
         pub fn crowdloan(&self) -> crowdloan::constants::ConstantsApi<'a, T> {
-            crowdloan::constants::ConstantsApi::new(self.client)
+            crowdloan::constants::ConstantsApi::from_metadata(self.metadata)
         }
     }
     pub struct StorageApi<'a, T: ::subxt::Config> {